@@ -9,16 +9,32 @@ pub const INVESTOR_RECORD_SEED: &[u8] = b"investor_record";
 pub const INVESTOR_PAGE_SEED: &[u8] = b"investor_page";
 pub const TREASURY_QUOTE_SEED: &[u8] = b"treasury_quote";
 pub const TREASURY_BASE_SEED: &[u8] = b"treasury_base";
+pub const PAGE_BITMAP_SEED: &[u8] = b"page_bitmap";
+pub const FEE_POSITION_SEED: &[u8] = b"fee_position";
 
 /// Time constants
 pub const SECONDS_PER_DAY: i64 = 86400;
+/// Bounds for configurable distribution periods
+pub const MIN_DISTRIBUTION_INTERVAL_SECS: i64 = 3600;
+pub const MAX_DISTRIBUTION_INTERVAL_SECS: i64 = 30 * SECONDS_PER_DAY;
+/// Minimum delay between queueing a vault config change and executing it
+pub const CONFIG_CHANGE_DELAY_SECS: i64 = 7 * SECONDS_PER_DAY;
 
 /// Distribution constants
-pub const MAX_INVESTORS_PER_PAGE: usize = 64;
+/// A full page must fit in one `distribute_page` transaction, which takes up to 4 accounts
+/// per investor on top of its fixed accounts
+pub const MAX_INVESTORS_PER_PAGE: usize = 10;
+/// Account keys a single transaction may reference
+pub const MAX_TX_ACCOUNT_LOCKS: usize = 64;
 pub const MAX_BPS: u16 = 10000;
+/// Investor pages tracked by each processed-page bitmap chunk
+pub const PAGES_PER_BITMAP_CHUNK: u32 = 1024;
 
 /// Meteora DLMM V2 Program ID (mainnet)
 pub const DLMM_PROGRAM_ID: Pubkey = solana_program::pubkey!("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo");
 
+/// SPL Memo program, required by DLMM instructions that support Token-2022 pairs
+pub const MEMO_PROGRAM_ID: Pubkey = solana_program::pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
 /// Streamflow Program ID (mainnet)
 pub const STREAMFLOW_PROGRAM_ID: Pubkey = solana_program::pubkey!("strmRqUCoQUgGUan5YhzUZa6KqdzwX5L6FpUxfmKg5m");
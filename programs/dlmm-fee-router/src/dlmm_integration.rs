@@ -1,9 +1,27 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount, Mint};
 use bytemuck::{Pod, Zeroable};
 
-/// DLMM V2 Pool State (simplified representation)
-#[repr(C)]
+/// Anchor instruction discriminator for DLMM `claim_fee` (sha256("global:claim_fee")[..8])
+pub const CLAIM_FEE_DISCRIMINATOR: [u8; 8] = [169, 32, 79, 137, 136, 232, 70, 137];
+
+/// Anchor instruction discriminator for DLMM `claim_fee2` (sha256("global:claim_fee2")[..8]),
+/// which takes a token program per side
+pub const CLAIM_FEE2_DISCRIMINATOR: [u8; 8] = [112, 191, 101, 171, 28, 144, 127, 187];
+
+/// Seed of the DLMM event authority PDA required by its `#[event_cpi]` instructions
+pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+
+/// Anchor instruction discriminator for DLMM `initialize_position` (sha256("global:initialize_position")[..8])
+pub const INITIALIZE_POSITION_DISCRIMINATOR: [u8; 8] = [219, 192, 234, 71, 190, 191, 102, 80];
+
+/// Anchor instruction discriminator for DLMM `close_position` (sha256("global:close_position")[..8])
+pub const CLOSE_POSITION_DISCRIMINATOR: [u8; 8] = [123, 134, 81, 0, 49, 68, 98, 98];
+
+/// Maximum number of bins a single DLMM position can span
+pub const MAX_BIN_PER_POSITION: i32 = 70;
+
+/// DLMM V2 Pool State (leading fields of the on-chain `LbPair` account)
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct LbPair {
     pub parameters: Parameters,
@@ -13,57 +31,53 @@ pub struct LbPair {
     pub pair_type: u8,
     pub active_id: i32,
     pub bin_step: u16,
-    pub protocol_fee: ProtocolFee,
-    pub padding1: [u8; 4],
-    pub last_updated_at: i64,
-    pub padding2: [u8; 8],
-    pub cumulative_fee_volume: CumulativeFeeVolume,
-    pub padding3: [u8; 8],
+    pub status: u8,
+    pub require_base_factor_seed: u8,
+    pub base_factor_seed: [u8; 2],
+    pub activation_type: u8,
+    pub padding0: u8,
     pub token_x_mint: Pubkey,
     pub token_y_mint: Pubkey,
     pub reserve_x: Pubkey,
     pub reserve_y: Pubkey,
-    pub padding4: [u8; 32],
-    pub oracle: Pubkey,
-    pub padding5: [u8; 64],
-    pub reserved: [u8; 64],
+    pub protocol_fee: ProtocolFee,
 }
 
-#[repr(C)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct Parameters {
-    pub swap_fee_bps: u16,
-    pub max_bin_id: i32,
+    pub base_factor: u16,
+    pub filter_period: u16,
+    pub decay_period: u16,
+    pub reduction_factor: u16,
+    pub variable_fee_control: u32,
+    pub max_volatility_accumulator: u32,
     pub min_bin_id: i32,
-    pub bin_count: u16,
+    pub max_bin_id: i32,
+    pub protocol_share: u16,
+    pub padding: [u8; 6],
 }
 
-#[repr(C)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct VParameters {
     pub volatility_accumulator: u32,
     pub volatility_reference: u32,
-    pub padding: [u8; 16],
+    pub index_reference: i32,
+    pub padding: [u8; 4],
+    pub last_update_timestamp: i64,
+    pub padding1: [u8; 8],
 }
 
-#[repr(C)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct ProtocolFee {
     pub amount_x: u64,
     pub amount_y: u64,
 }
 
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct CumulativeFeeVolume {
-    pub cumulative_fee_x: u128,
-    pub cumulative_fee_y: u128,
-    pub cumulative_volume_x: u128,
-    pub cumulative_volume_y: u128,
-}
-
 /// Position state in DLMM
-#[repr(C)]
+#[repr(C, packed)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct Position {
     pub lb_pair: Pubkey,
@@ -91,29 +105,29 @@ pub fn calculate_quote_only_ticks(
     );
     
     let current_tick = pool.active_id;
-    let tick_spacing = pool.bin_step as i32;
+    let width = MAX_BIN_PER_POSITION;
     
     // Calculate position range that will only accrue quote fees
     let (tick_lower, tick_upper) = if is_quote_x {
         // Quote is token X: Create position below current price
         // This ensures we only collect fees when quote appreciates
-        let tick_upper = current_tick.saturating_sub(tick_spacing);
-        let tick_lower = tick_upper.saturating_sub(tick_spacing * 100);
+        let tick_upper = current_tick.saturating_sub(1);
+        let tick_lower = tick_upper.saturating_sub(width - 1);
         (tick_lower, tick_upper)
     } else {
         // Quote is token Y: Create position above current price
-        let tick_lower = current_tick.saturating_add(tick_spacing);
-        let tick_upper = tick_lower.saturating_add(tick_spacing * 100);
+        let tick_lower = current_tick.saturating_add(1);
+        let tick_upper = tick_lower.saturating_add(width - 1);
         (tick_lower, tick_upper)
     };
     
     // Validate the ticks are within bounds
     require!(
-        tick_lower >= pool.parameters.min_bin_id,
+        tick_lower >= { pool.parameters.min_bin_id },
         crate::errors::FeeRouterError::InvalidPoolConfiguration
     );
     require!(
-        tick_upper <= pool.parameters.max_bin_id,
+        tick_upper <= { pool.parameters.max_bin_id },
         crate::errors::FeeRouterError::InvalidPoolConfiguration
     );
     
@@ -178,24 +192,26 @@ pub fn extract_quote_fees(
 
 /// Helper to deserialize DLMM accounts safely
 pub fn deserialize_lb_pair(account: &AccountInfo) -> Result<LbPair> {
-    if account.data_len() < std::mem::size_of::<LbPair>() {
+    let len = std::mem::size_of::<LbPair>();
+    if account.data_len() < 8 + len {
         return Err(crate::errors::FeeRouterError::InvalidPoolConfiguration.into());
     }
     
     let data = account.try_borrow_data()?;
-    let pool = bytemuck::try_from_bytes::<LbPair>(&data[8..]) // Skip discriminator
+    let pool = bytemuck::try_from_bytes::<LbPair>(&data[8..8 + len]) // Skip discriminator
         .map_err(|_| crate::errors::FeeRouterError::InvalidPoolConfiguration)?;
     
     Ok(*pool)
 }
 
 pub fn deserialize_position(account: &AccountInfo) -> Result<Position> {
-    if account.data_len() < std::mem::size_of::<Position>() {
+    let len = std::mem::size_of::<Position>();
+    if account.data_len() < 8 + len {
         return Err(crate::errors::FeeRouterError::PositionNotInitialized.into());
     }
     
     let data = account.try_borrow_data()?;
-    let position = bytemuck::try_from_bytes::<Position>(&data[8..]) // Skip discriminator
+    let position = bytemuck::try_from_bytes::<Position>(&data[8..8 + len]) // Skip discriminator
         .map_err(|_| crate::errors::FeeRouterError::PositionNotInitialized)?;
     
     Ok(*position)
@@ -205,37 +221,97 @@ pub fn deserialize_position(account: &AccountInfo) -> Result<Position> {
 pub mod cpi {
     use super::*;
     
-    pub fn create_honorary_position<'info>(
-        dlmm_program: AccountInfo<'info>,
-        pool: AccountInfo<'info>,
-        position: AccountInfo<'info>,
-        position_owner: AccountInfo<'info>,
-        system_program: AccountInfo<'info>,
-        rent: AccountInfo<'info>,
-        tick_lower: i32,
-        tick_upper: i32,
+    /// Accounts of DLMM `initialize_position`
+    pub struct InitializePosition<'info> {
+        pub payer: AccountInfo<'info>,
+        pub position: AccountInfo<'info>,
+        pub lb_pair: AccountInfo<'info>,
+        pub owner: AccountInfo<'info>,
+        pub system_program: AccountInfo<'info>,
+        pub rent: AccountInfo<'info>,
+        pub event_authority: AccountInfo<'info>,
+        pub program: AccountInfo<'info>,
+    }
+    
+    /// Accounts of DLMM `close_position`
+    pub struct ClosePosition<'info> {
+        pub position: AccountInfo<'info>,
+        pub lb_pair: AccountInfo<'info>,
+        pub bin_array_lower: AccountInfo<'info>,
+        pub bin_array_upper: AccountInfo<'info>,
+        pub sender: AccountInfo<'info>,
+        pub rent_receiver: AccountInfo<'info>,
+        pub event_authority: AccountInfo<'info>,
+        pub program: AccountInfo<'info>,
+    }
+    
+    /// Accounts of DLMM `claim_fee`
+    pub struct ClaimFee<'info> {
+        pub lb_pair: AccountInfo<'info>,
+        pub position: AccountInfo<'info>,
+        pub bin_array_lower: AccountInfo<'info>,
+        pub bin_array_upper: AccountInfo<'info>,
+        pub sender: AccountInfo<'info>,
+        pub reserve_x: AccountInfo<'info>,
+        pub reserve_y: AccountInfo<'info>,
+        pub user_token_x: AccountInfo<'info>,
+        pub user_token_y: AccountInfo<'info>,
+        pub token_x_mint: AccountInfo<'info>,
+        pub token_y_mint: AccountInfo<'info>,
+        pub token_program: AccountInfo<'info>,
+        pub event_authority: AccountInfo<'info>,
+        pub program: AccountInfo<'info>,
+    }
+    
+    /// Accounts of DLMM `claim_fee2`; the bin arrays covering the position follow as
+    /// remaining accounts
+    pub struct ClaimFee2<'info> {
+        pub lb_pair: AccountInfo<'info>,
+        pub position: AccountInfo<'info>,
+        pub sender: AccountInfo<'info>,
+        pub reserve_x: AccountInfo<'info>,
+        pub reserve_y: AccountInfo<'info>,
+        pub user_token_x: AccountInfo<'info>,
+        pub user_token_y: AccountInfo<'info>,
+        pub token_x_mint: AccountInfo<'info>,
+        pub token_y_mint: AccountInfo<'info>,
+        pub token_program_x: AccountInfo<'info>,
+        pub token_program_y: AccountInfo<'info>,
+        pub memo_program: AccountInfo<'info>,
+        pub event_authority: AccountInfo<'info>,
+        pub program: AccountInfo<'info>,
+        pub bin_arrays: Vec<AccountInfo<'info>>,
+    }
+    
+    /// Create the honorary (zero-liquidity) position via Meteora DLMM `initialize_position`
+    pub fn create_honorary_position(
+        accounts: InitializePosition,
+        lower_bin_id: i32,
+        width: i32,
         signer_seeds: &[&[&[u8]]],
     ) -> Result<()> {
         // Prepare instruction data
-        let mut data = Vec::with_capacity(12);
-        data.extend_from_slice(&[0x01]); // InitializePosition instruction discriminator
-        data.extend_from_slice(&tick_lower.to_le_bytes());
-        data.extend_from_slice(&tick_upper.to_le_bytes());
-        data.extend_from_slice(&0u32.to_le_bytes()); // 0 liquidity
+        let mut data = Vec::with_capacity(16);
+        data.extend_from_slice(&INITIALIZE_POSITION_DISCRIMINATOR);
+        data.extend_from_slice(&lower_bin_id.to_le_bytes());
+        data.extend_from_slice(&width.to_le_bytes());
         
-        // Prepare accounts
-        let accounts = vec![
-            AccountMeta::new(position.key(), false),
-            AccountMeta::new_readonly(pool.key(), false),
-            AccountMeta::new_readonly(position_owner.key(), true),
-            AccountMeta::new_readonly(system_program.key(), false),
-            AccountMeta::new_readonly(rent.key(), false),
+        // Account order follows the DLMM `InitializePosition` accounts struct
+        let account_metas = vec![
+            AccountMeta::new(accounts.payer.key(), true),
+            AccountMeta::new(accounts.position.key(), true),
+            AccountMeta::new_readonly(accounts.lb_pair.key(), false),
+            AccountMeta::new_readonly(accounts.owner.key(), true),
+            AccountMeta::new_readonly(accounts.system_program.key(), false),
+            AccountMeta::new_readonly(accounts.rent.key(), false),
+            AccountMeta::new_readonly(accounts.event_authority.key(), false),
+            AccountMeta::new_readonly(accounts.program.key(), false),
         ];
         
         // Create instruction
         let instruction = solana_program::instruction::Instruction {
-            program_id: dlmm_program.key(),
-            accounts,
+            program_id: accounts.program.key(),
+            accounts: account_metas,
             data,
         };
         
@@ -243,11 +319,14 @@ pub mod cpi {
         anchor_lang::solana_program::program::invoke_signed(
             &instruction,
             &[
-                position,
-                pool,
-                position_owner,
-                system_program,
-                rent,
+                accounts.payer,
+                accounts.position,
+                accounts.lb_pair,
+                accounts.owner,
+                accounts.system_program,
+                accounts.rent,
+                accounts.event_authority,
+                accounts.program,
             ],
             signer_seeds,
         )?;
@@ -255,91 +334,168 @@ pub mod cpi {
         Ok(())
     }
     
-    pub fn claim_position_fees<'info>(
-        dlmm_program: AccountInfo<'info>,
-        position: AccountInfo<'info>,
-        pool: AccountInfo<'info>,
-        position_owner: AccountInfo<'info>,
-        reserve_x: AccountInfo<'info>,
-        reserve_y: AccountInfo<'info>,
-        user_token_x: AccountInfo<'info>,
-        user_token_y: AccountInfo<'info>,
-        token_program: AccountInfo<'info>,
-        signer_seeds: &[&[&[u8]]],
-    ) -> Result<(u64, u64)> {
-        // Prepare instruction data
-        let data = vec![0x02]; // ClaimFees instruction discriminator
+    /// Close an empty position via Meteora DLMM `close_position`, returning its rent
+    pub fn close_position(accounts: ClosePosition, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        // `close_position` takes no arguments beyond the discriminator
+        let data = CLOSE_POSITION_DISCRIMINATOR.to_vec();
         
-        // Prepare accounts
-        let accounts = vec![
-            AccountMeta::new(position.key(), false),
-            AccountMeta::new(pool.key(), false),
-            AccountMeta::new_readonly(position_owner.key(), true),
-            AccountMeta::new(reserve_x.key(), false),
-            AccountMeta::new(reserve_y.key(), false),
-            AccountMeta::new(user_token_x.key(), false),
-            AccountMeta::new(user_token_y.key(), false),
-            AccountMeta::new_readonly(token_program.key(), false),
+        // Account order follows the DLMM `ClosePosition` accounts struct
+        let account_metas = vec![
+            AccountMeta::new(accounts.position.key(), false),
+            AccountMeta::new(accounts.lb_pair.key(), false),
+            AccountMeta::new(accounts.bin_array_lower.key(), false),
+            AccountMeta::new(accounts.bin_array_upper.key(), false),
+            AccountMeta::new_readonly(accounts.sender.key(), true),
+            AccountMeta::new(accounts.rent_receiver.key(), false),
+            AccountMeta::new_readonly(accounts.event_authority.key(), false),
+            AccountMeta::new_readonly(accounts.program.key(), false),
         ];
         
         // Create instruction
         let instruction = solana_program::instruction::Instruction {
-            program_id: dlmm_program.key(),
-            accounts,
+            program_id: accounts.program.key(),
+            accounts: account_metas,
             data,
         };
         
-        // Get balances before
-        let balance_x_before = {
-            let account = user_token_x.try_borrow_data()?;
-            let token_account = bytemuck::try_from_bytes::<TokenAccount>(&account[..])
-                .map_err(|_| ProgramError::InvalidAccountData)?;
-            token_account.amount
-        };
+        // Invoke CPI
+        anchor_lang::solana_program::program::invoke_signed(
+            &instruction,
+            &[
+                accounts.position,
+                accounts.lb_pair,
+                accounts.bin_array_lower,
+                accounts.bin_array_upper,
+                accounts.sender,
+                accounts.rent_receiver,
+                accounts.event_authority,
+                accounts.program,
+            ],
+            signer_seeds,
+        )?;
+        
+        Ok(())
+    }
+    
+    /// Claim accrued swap fees from a position via Meteora DLMM `claim_fee`
+    pub fn claim_position_fees(accounts: ClaimFee, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        // `claim_fee` takes no arguments beyond the discriminator
+        let data = CLAIM_FEE_DISCRIMINATOR.to_vec();
+        
+        // Account order follows the DLMM `ClaimFee` accounts struct
+        let account_metas = vec![
+            AccountMeta::new(accounts.lb_pair.key(), false),
+            AccountMeta::new(accounts.position.key(), false),
+            AccountMeta::new(accounts.bin_array_lower.key(), false),
+            AccountMeta::new(accounts.bin_array_upper.key(), false),
+            AccountMeta::new_readonly(accounts.sender.key(), true),
+            AccountMeta::new(accounts.reserve_x.key(), false),
+            AccountMeta::new(accounts.reserve_y.key(), false),
+            AccountMeta::new(accounts.user_token_x.key(), false),
+            AccountMeta::new(accounts.user_token_y.key(), false),
+            AccountMeta::new_readonly(accounts.token_x_mint.key(), false),
+            AccountMeta::new_readonly(accounts.token_y_mint.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+            AccountMeta::new_readonly(accounts.event_authority.key(), false),
+            AccountMeta::new_readonly(accounts.program.key(), false),
+        ];
         
-        let balance_y_before = {
-            let account = user_token_y.try_borrow_data()?;
-            let token_account = bytemuck::try_from_bytes::<TokenAccount>(&account[..])
-                .map_err(|_| ProgramError::InvalidAccountData)?;
-            token_account.amount
+        // Create instruction
+        let instruction = solana_program::instruction::Instruction {
+            program_id: accounts.program.key(),
+            accounts: account_metas,
+            data,
         };
         
         // Invoke CPI
         anchor_lang::solana_program::program::invoke_signed(
             &instruction,
             &[
-                position,
-                pool,
-                position_owner,
-                reserve_x,
-                reserve_y,
-                user_token_x.clone(),
-                user_token_y.clone(),
-                token_program,
+                accounts.lb_pair,
+                accounts.position,
+                accounts.bin_array_lower,
+                accounts.bin_array_upper,
+                accounts.sender,
+                accounts.reserve_x,
+                accounts.reserve_y,
+                accounts.user_token_x,
+                accounts.user_token_y,
+                accounts.token_x_mint,
+                accounts.token_y_mint,
+                accounts.token_program,
+                accounts.event_authority,
+                accounts.program,
             ],
             signer_seeds,
         )?;
         
-        // Get balances after
-        let balance_x_after = {
-            let account = user_token_x.try_borrow_data()?;
-            let token_account = bytemuck::try_from_bytes::<TokenAccount>(&account[..])
-                .map_err(|_| ProgramError::InvalidAccountData)?;
-            token_account.amount
-        };
+        Ok(())
+    }
+    
+    /// Claim accrued swap fees via Meteora DLMM `claim_fee2`, for pairs whose X and Y mints
+    /// live under different token programs (SPL Token and Token-2022)
+    pub fn claim_position_fees2(
+        accounts: ClaimFee2,
+        min_bin_id: i32,
+        max_bin_id: i32,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        // Args: min_bin_id, max_bin_id and an empty `RemainingAccountsInfo` (no transfer hooks)
+        let mut data = Vec::with_capacity(20);
+        data.extend_from_slice(&CLAIM_FEE2_DISCRIMINATOR);
+        data.extend_from_slice(&min_bin_id.to_le_bytes());
+        data.extend_from_slice(&max_bin_id.to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
         
-        let balance_y_after = {
-            let account = user_token_y.try_borrow_data()?;
-            let token_account = bytemuck::try_from_bytes::<TokenAccount>(&account[..])
-                .map_err(|_| ProgramError::InvalidAccountData)?;
-            token_account.amount
+        // Account order follows the DLMM `ClaimFee2` accounts struct
+        let mut account_metas = vec![
+            AccountMeta::new(accounts.lb_pair.key(), false),
+            AccountMeta::new(accounts.position.key(), false),
+            AccountMeta::new_readonly(accounts.sender.key(), true),
+            AccountMeta::new(accounts.reserve_x.key(), false),
+            AccountMeta::new(accounts.reserve_y.key(), false),
+            AccountMeta::new(accounts.user_token_x.key(), false),
+            AccountMeta::new(accounts.user_token_y.key(), false),
+            AccountMeta::new_readonly(accounts.token_x_mint.key(), false),
+            AccountMeta::new_readonly(accounts.token_y_mint.key(), false),
+            AccountMeta::new_readonly(accounts.token_program_x.key(), false),
+            AccountMeta::new_readonly(accounts.token_program_y.key(), false),
+            AccountMeta::new_readonly(accounts.memo_program.key(), false),
+            AccountMeta::new_readonly(accounts.event_authority.key(), false),
+            AccountMeta::new_readonly(accounts.program.key(), false),
+        ];
+        account_metas.extend(
+            accounts.bin_arrays.iter().map(|bin_array| AccountMeta::new(bin_array.key(), false)),
+        );
+        
+        // Create instruction
+        let instruction = solana_program::instruction::Instruction {
+            program_id: accounts.program.key(),
+            accounts: account_metas,
+            data,
         };
         
-        // Calculate claimed amounts
-        let claimed_x = balance_x_after.saturating_sub(balance_x_before);
-        let claimed_y = balance_y_after.saturating_sub(balance_y_before);
+        // Invoke CPI
+        let mut account_infos = vec![
+            accounts.lb_pair,
+            accounts.position,
+            accounts.sender,
+            accounts.reserve_x,
+            accounts.reserve_y,
+            accounts.user_token_x,
+            accounts.user_token_y,
+            accounts.token_x_mint,
+            accounts.token_y_mint,
+            accounts.token_program_x,
+            accounts.token_program_y,
+            accounts.memo_program,
+            accounts.event_authority,
+            accounts.program,
+        ];
+        account_infos.extend(accounts.bin_arrays);
+        anchor_lang::solana_program::program::invoke_signed(&instruction, &account_infos, signer_seeds)?;
         
-        Ok((claimed_x, claimed_y))
+        Ok(())
     }
 }
 
@@ -364,7 +520,7 @@ mod tests {
         assert!(result.is_ok());
         
         let (tick_lower, tick_upper) = result.unwrap();
-        assert!(tick_upper < pool.active_id);
+        assert!(tick_upper < { pool.active_id });
         assert!(tick_lower < tick_upper);
         
         // Test with quote as token Y
@@ -375,10 +531,20 @@ mod tests {
         assert!(result.is_ok());
         
         let (tick_lower, tick_upper) = result.unwrap();
-        assert!(tick_lower > pool.active_id);
+        assert!(tick_lower > { pool.active_id });
+        assert_eq!(tick_upper - tick_lower + 1, MAX_BIN_PER_POSITION);
         assert!(tick_lower < tick_upper);
     }
     
+    #[test]
+    fn test_lb_pair_layout() {
+        // Mint offsets (after the 8-byte discriminator) match the on-chain account
+        assert_eq!(8 + std::mem::offset_of!(LbPair, token_x_mint), 88);
+        assert_eq!(8 + std::mem::offset_of!(LbPair, token_y_mint), 120);
+        assert_eq!(std::mem::size_of::<Parameters>(), 32);
+        assert_eq!(std::mem::size_of::<VParameters>(), 32);
+    }
+    
     #[test]
     fn test_quote_fee_extraction() {
         let mut pool = unsafe { std::mem::zeroed::<LbPair>() };
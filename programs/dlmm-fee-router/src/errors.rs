@@ -5,7 +5,7 @@ pub enum FeeRouterError {
     #[msg("Invalid fee share BPS, must be <= 10000")]
     InvalidFeeShareBps,
     
-    #[msg("Distribution window not reached (24h required)")]
+    #[msg("Distribution window not reached")]
     DistributionWindowNotReached,
     
     #[msg("Position would accrue base fees, only quote-only positions allowed")]
@@ -56,6 +56,66 @@ pub enum FeeRouterError {
     #[msg("Distribution already executed for the given page")]
     PageAlreadyProcessed,
 
-    #[msg("Day not started; call page 0 first to claim fees")]
+    #[msg("Day not started; call claim_day_fees first")]
     DayNotStarted,
+
+    #[msg("Invalid Streamflow stream account")]
+    InvalidStreamAccount,
+
+    #[msg("Treasury balance does not cover undistributed funds")]
+    TreasuryBalanceMismatch,
+
+    #[msg("Distribution outflows exceed the day's inflows")]
+    DistributionExceedsInflow,
+
+    #[msg("Investor pages have not all been snapshotted for the day")]
+    SnapshotIncomplete,
+
+    #[msg("A distribution day is in progress")]
+    DayInProgress,
+
+    #[msg("Investor pages remain to be distributed for the day")]
+    PagesRemaining,
+
+    #[msg("Invalid distribution epoch configuration")]
+    InvalidEpochConfig,
+
+    #[msg("Distribution interval out of bounds")]
+    InvalidDistributionInterval,
+
+    #[msg("Config change must take effect after the timelock delay")]
+    ConfigChangeTooSoon,
+
+    #[msg("A config change is already pending")]
+    ConfigChangePending,
+
+    #[msg("No config change is pending")]
+    NoPendingConfigChange,
+
+    #[msg("Config change timelock has not elapsed")]
+    ConfigTimelockNotElapsed,
+
+    #[msg("Vault is paused")]
+    VaultPaused,
+
+    #[msg("Vault must be paused")]
+    VaultNotPaused,
+
+    #[msg("Investor accounts must be closed first")]
+    InvestorAccountsRemaining,
+
+    #[msg("Position fees already claimed for the upcoming day")]
+    PositionAlreadyClaimed,
+
+    #[msg("Not every fee position has been claimed for the day")]
+    PositionClaimsIncomplete,
+
+    #[msg("Memo program required to claim from a mixed token program pair")]
+    MemoProgramRequired,
+
+    #[msg("A snapshot is already under way; only the admin may restart it")]
+    SnapshotInProgress,
+
+    #[msg("Position must be claimed for the upcoming day before it is removed")]
+    PositionNotClaimed,
 }
@@ -1,21 +1,58 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{EpochConfig, VaultConfig};
+
 #[event]
 pub struct VaultInitialized {
     pub vault_id: [u8; 32],
+    pub admin: Pubkey,
     pub creator: Pubkey,
     pub investor_fee_share_bps: u16,
     pub min_payout_lamports: u64,
     pub daily_cap_lamports: Option<u64>,
+    pub roll_over_empty_days: bool,
+    pub accrual_mode: bool,
+    pub distribution_interval_secs: i64,
+    pub epoch: Option<EpochConfig>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminProposed {
+    pub vault_id: [u8; 32],
+    pub current_admin: Pubkey,
+    pub proposed_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub vault_id: [u8; 32],
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
     pub timestamp: i64,
 }
 
 #[event]
 pub struct HonoraryPositionInitialized {
     pub vault_id: [u8; 32],
+    pub position_index: u32,
     pub position_pubkey: Pubkey,
     pub pool_pubkey: Pubkey,
     pub quote_mint: Pubkey,
+    pub lower_bin_id: i32,
+    pub upper_bin_id: i32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InvestorDataUpdated {
+    pub vault_id: [u8; 32],
+    pub page: u32,
+    pub page_investor_count: u32,
+    pub investor_count: u32,
+    pub investor_page_count: u32,
+    pub total_investor_allocation: u64,
     pub timestamp: i64,
 }
 
@@ -24,16 +61,27 @@ pub struct QuoteFeesClaimed {
     pub vault_id: [u8; 32],
     pub amount_claimed: u64,
     pub carry_over_prev: u64,
+    pub investor_pool: u64,
     pub timestamp: i64,
     pub distribution_day: u64,
 }
 
+#[event]
+pub struct EmptyDayRolledOver {
+    pub vault_id: [u8; 32],
+    pub carry_over: u64,
+    pub distribution_day: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct InvestorPayoutPage {
     pub vault_id: [u8; 32],
     pub page: u32,
     pub total_payout: u64,
     pub investor_count: u32,
+    pub atas_created: u32,
+    pub ata_rent_reimbursed: u64,
     pub daily_distributed_after: u64,
     pub timestamp: i64,
 }
@@ -42,7 +90,10 @@ pub struct InvestorPayoutPage {
 pub struct CreatorPayoutDayClosed {
     pub vault_id: [u8; 32],
     pub creator_payout: u64,
+    pub creator_transfer_fee: u64,
+    pub forfeited_to_creator: u64,
     pub total_distributed_to_investors: u64,
+    pub carry_over: u64,
     pub distribution_day: u64,
     pub timestamp: i64,
 }
@@ -52,7 +103,136 @@ pub struct InvestorPayout {
     pub vault_id: [u8; 32],
     pub investor: Pubkey,
     pub amount: u64,
+    pub transfer_fee: u64,
     pub locked_amount: u64,
     pub weight: u64,
+    pub accrued: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InvestorPageSnapshotted {
+    pub vault_id: [u8; 32],
+    pub page: u32,
+    pub page_locked: u64,
+    pub day_total_locked: u64,
+    pub pages_snapshotted: u32,
+    pub snapshot_ts: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultConfigQueued {
+    pub vault_id: [u8; 32],
+    pub admin: Pubkey,
+    pub current_config: VaultConfig,
+    pub new_config: VaultConfig,
+    pub effective_ts: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultConfigUpdated {
+    pub vault_id: [u8; 32],
+    pub executor: Pubkey,
+    pub old_config: VaultConfig,
+    pub new_config: VaultConfig,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultConfigChangeCancelled {
+    pub vault_id: [u8; 32],
+    pub admin: Pubkey,
+    pub cancelled_config: VaultConfig,
+    pub effective_ts: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GuardianUpdated {
+    pub vault_id: [u8; 32],
+    pub previous_guardian: Option<Pubkey>,
+    pub new_guardian: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PauseStateChanged {
+    pub vault_id: [u8; 32],
+    pub authority: Pubkey,
+    pub paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InvestorPageClosed {
+    pub vault_id: [u8; 32],
+    pub page: u32,
+    pub records_closed: u32,
+    pub investor_count: u32,
+    pub total_investor_allocation: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultClosed {
+    pub vault_id: [u8; 32],
+    pub admin: Pubkey,
+    pub swept_to_creator: u64,
+    pub sweep_transfer_fee: u64,
+    pub base_swept_to_creator: u64,
+    pub carry_over_forfeited: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InvestorFeesClaimed {
+    pub vault_id: [u8; 32],
+    pub investor: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub transfer_fee: u64,
+    pub total_fees_received: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PositionFeesClaimed {
+    pub vault_id: [u8; 32],
+    pub position_index: u32,
+    pub position: Pubkey,
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub positions_claimed: u32,
+    pub pending_claimed_fees: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeePositionRemoved {
+    pub vault_id: [u8; 32],
+    pub position_index: u32,
+    pub position: Pubkey,
+    pub pool: Pubkey,
+    pub position_count: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InvestorPayoutCarriedForward {
+    pub vault_id: [u8; 32],
+    pub investor: Pubkey,
+    pub amount: u64,
+    pub distribution_day: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InvestorPageSkipped {
+    pub vault_id: [u8; 32],
+    pub page: u32,
+    pub carried_over: u64,
+    pub distribution_day: u64,
     pub timestamp: i64,
 }
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::FeeRouterError,
+    events::AdminTransferred,
+    state::Vault,
+};
+
+#[derive(Accounts)]
+#[instruction(vault_id: [u8; 32])]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault_id.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_initialized,
+        constraint = vault.pending_admin == Some(new_admin.key()) @ FeeRouterError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub new_admin: Signer<'info>,
+}
+
+pub fn accept_admin(
+    ctx: Context<AcceptAdmin>,
+    vault_id: [u8; 32],
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let previous_admin = vault.admin;
+    
+    vault.admin = ctx.accounts.new_admin.key();
+    vault.pending_admin = None;
+    
+    emit!(AdminTransferred {
+        vault_id,
+        previous_admin,
+        new_admin: vault.admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::FeeRouterError,
+    events::VaultConfigChangeCancelled,
+    state::Vault,
+};
+
+#[derive(Accounts)]
+#[instruction(vault_id: [u8; 32])]
+pub struct CancelVaultConfig<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault_id.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_initialized,
+        constraint = vault.admin == admin.key() @ FeeRouterError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn cancel_vault_config(
+    ctx: Context<CancelVaultConfig>,
+    vault_id: [u8; 32],
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let pending = vault.pending_config
+        .take()
+        .ok_or(FeeRouterError::NoPendingConfigChange)?;
+    
+    emit!(VaultConfigChangeCancelled {
+        vault_id,
+        admin: vault.admin,
+        cancelled_config: pending.config,
+        effective_ts: pending.effective_ts,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    constants::*,
+    errors::FeeRouterError,
+    events::{QuoteFeesClaimed, EmptyDayRolledOver},
+    state::{Vault, DistributionState},
+    utils::mul_div,
+};
+
+#[derive(Accounts)]
+#[instruction(vault_id: [u8; 32])]
+pub struct ClaimDayFees<'info> {
+    #[account(
+        seeds = [VAULT_SEED, vault_id.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_initialized,
+        constraint = vault.position_initialized,
+        constraint = !vault.paused @ FeeRouterError::VaultPaused
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [DISTRIBUTION_STATE_SEED, vault_id.as_ref()],
+        bump = distribution_state.bump
+    )]
+    pub distribution_state: Box<Account<'info, DistributionState>>,
+
+    /// Program-owned quote treasury ATA (balance checked against the accounted funds)
+    #[account(
+        constraint = treasury_quote.key() == vault.treasury_quote,
+        constraint = treasury_quote.mint == vault.quote_mint @ FeeRouterError::InvalidQuoteMint
+    )]
+    pub treasury_quote: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub crank_operator: Signer<'info>,
+}
+
+pub fn claim_day_fees(
+    ctx: Context<ClaimDayFees>,
+    vault_id: [u8; 32],
+) -> Result<()> {
+    let current_ts = Clock::get()?.unix_timestamp;
+    let vault = &ctx.accounts.vault;
+    let distribution_state = &mut ctx.accounts.distribution_state;
+
+    // An unfinished day must be resumed from `current_page`, never replaced
+    require!(!distribution_state.is_day_open(), FeeRouterError::DayInProgress);
+
+    require!(
+        distribution_state.can_distribute(current_ts, vault),
+        FeeRouterError::DistributionWindowNotReached
+    );
+
+    // Every investor page must be snapshotted so the pool reflects the whole day's locked total;
+    // the day closes on its last page, so it needs at least one
+    require!(
+        distribution_state.pages_snapshotted == vault.investor_page_count,
+        FeeRouterError::SnapshotIncomplete
+    );
+    require!(vault.investor_page_count > 0, FeeRouterError::InvalidPageNumber);
+
+    // Every registered position must have been claimed (`claim_position_fees`) for the day
+    require!(
+        distribution_state.positions_claimed == vault.position_count,
+        FeeRouterError::PositionClaimsIncomplete
+    );
+
+    // Carried-over, accrued and freshly claimed funds must still be sitting in the treasury
+    require!(
+        ctx.accounts.treasury_quote.amount
+            >= distribution_state.carry_over
+                .saturating_add(distribution_state.fees_owed)
+                .saturating_add(distribution_state.pending_claimed_fees),
+        FeeRouterError::TreasuryBalanceMismatch
+    );
+
+    // Start new distribution day with the quote fees summed across all positions,
+    // paying out the pages recorded by the snapshot
+    let claimed_amount = distribution_state.take_pending_claims();
+    distribution_state.start_new_day(current_ts, vault);
+    distribution_state.day_claimed_fees = claimed_amount;
+    distribution_state.day_page_count = distribution_state.pages_snapshotted;
+    let carry_over_prev = distribution_state.carry_over;
+
+    // Nothing was claimed: either fail, or close the day and keep the carry-over for the next one
+    if claimed_amount == 0 {
+        require!(vault.roll_over_empty_days, FeeRouterError::NoFeesToClaim);
+        distribution_state.close_day();
+
+        emit!(QuoteFeesClaimed {
+            vault_id,
+            amount_claimed: claimed_amount,
+            carry_over_prev,
+            investor_pool: 0,
+            timestamp: current_ts,
+            distribution_day: distribution_state.current_day,
+        });
+
+        emit!(EmptyDayRolledOver {
+            vault_id,
+            carry_over: distribution_state.carry_over,
+            distribution_day: distribution_state.current_day,
+            timestamp: current_ts,
+        });
+        return Ok(());
+    }
+
+    // Prior carry-over joins today's investor pool, frozen against the whole-day locked total
+    distribution_state.take_carry_over();
+    distribution_state.day_investor_pool = calculate_investor_pool(vault, distribution_state)?;
+
+    emit!(QuoteFeesClaimed {
+        vault_id,
+        amount_claimed: claimed_amount,
+        carry_over_prev,
+        investor_pool: distribution_state.day_investor_pool,
+        timestamp: current_ts,
+        distribution_day: distribution_state.current_day,
+    });
+
+    Ok(())
+}
+
+/// Investor share of the day's claimed fees (capped by the locked fraction of Y0) plus carry-in
+fn calculate_investor_pool(vault: &Vault, distribution_state: &DistributionState) -> Result<u64> {
+    let f_locked = mul_div(
+        distribution_state.day_total_locked,
+        MAX_BPS as u64,
+        vault.total_investor_allocation,
+    )?;
+    let eligible_investor_share_bps = u64::min(vault.investor_fee_share_bps as u64, f_locked);
+
+    mul_div(distribution_state.day_claimed_fees, eligible_investor_share_bps, MAX_BPS as u64)?
+        .checked_add(distribution_state.day_carry_in)
+        .ok_or(FeeRouterError::MathOverflow.into())
+}
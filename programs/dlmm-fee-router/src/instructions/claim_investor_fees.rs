@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    constants::*,
+    errors::FeeRouterError,
+    events::InvestorFeesClaimed,
+    state::{Vault, DistributionState, InvestorRecord},
+    token_integration,
+};
+
+#[derive(Accounts)]
+#[instruction(vault_id: [u8; 32])]
+pub struct ClaimInvestorFees<'info> {
+    #[account(
+        seeds = [VAULT_SEED, vault_id.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_initialized,
+        constraint = !vault.paused @ FeeRouterError::VaultPaused
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [DISTRIBUTION_STATE_SEED, vault_id.as_ref()],
+        bump = distribution_state.bump
+    )]
+    pub distribution_state: Box<Account<'info, DistributionState>>,
+
+    /// The signing investor's record
+    #[account(
+        mut,
+        seeds = [INVESTOR_RECORD_SEED, vault_id.as_ref(), investor.key().as_ref()],
+        bump = investor_record.bump,
+        constraint = investor_record.vault == vault.key() @ FeeRouterError::InvalidInvestorData
+    )]
+    pub investor_record: Box<Account<'info, InvestorRecord>>,
+
+    /// Any quote token account owned by the investor
+    #[account(
+        mut,
+        constraint = destination.owner == investor.key() @ FeeRouterError::InvalidInvestorData,
+        constraint = destination.mint == vault.quote_mint @ FeeRouterError::InvalidQuoteMint
+    )]
+    pub destination: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Program-owned quote treasury ATA
+    #[account(
+        mut,
+        constraint = treasury_quote.key() == vault.treasury_quote
+    )]
+    pub treasury_quote: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = quote_mint.key() == vault.quote_mint @ FeeRouterError::InvalidQuoteMint
+    )]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The treasury owner PDA
+    /// CHECK: PDA derivation
+    #[account(
+        seeds = [VAULT_SEED, vault_id.as_ref(), INVESTOR_FEE_POSITION_OWNER_SEED],
+        bump
+    )]
+    pub fee_position_owner: AccountInfo<'info>,
+
+    pub investor: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn claim_investor_fees(
+    ctx: Context<ClaimInvestorFees>,
+    vault_id: [u8; 32],
+) -> Result<()> {
+    let amount = ctx.accounts.investor_record.fees_owed;
+    require!(amount > 0, FeeRouterError::NoFeesToClaim);
+
+    // The mint may withhold a transfer fee from what reaches the investor
+    let transfer_fee = token_integration::transfer_from_treasury(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.treasury_quote.to_account_info(),
+        &ctx.accounts.quote_mint,
+        ctx.accounts.destination.to_account_info(),
+        ctx.accounts.fee_position_owner.to_account_info(),
+        &[&[
+            VAULT_SEED,
+            vault_id.as_ref(),
+            INVESTOR_FEE_POSITION_OWNER_SEED,
+            &[ctx.bumps.fee_position_owner],
+        ]],
+        amount,
+    )?;
+    let received = amount - transfer_fee;
+
+    let record = &mut ctx.accounts.investor_record;
+    record.fees_owed = 0;
+    record.total_fees_received = record.total_fees_received
+        .checked_add(received)
+        .ok_or(FeeRouterError::MathOverflow)?;
+
+    let distribution_state = &mut ctx.accounts.distribution_state;
+    distribution_state.fees_owed = distribution_state.fees_owed
+        .checked_sub(amount)
+        .ok_or(FeeRouterError::MathOverflow)?;
+
+    emit!(InvestorFeesClaimed {
+        vault_id,
+        investor: record.investor,
+        destination: ctx.accounts.destination.key(),
+        amount: received,
+        transfer_fee,
+        total_fees_received: record.total_fees_received,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
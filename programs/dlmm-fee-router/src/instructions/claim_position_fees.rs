@@ -0,0 +1,298 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+
+use crate::{
+    constants::*,
+    errors::FeeRouterError,
+    events::PositionFeesClaimed,
+    state::{Vault, DistributionState, FeePositionRecord},
+    dlmm_integration,
+};
+
+#[derive(Accounts)]
+#[instruction(vault_id: [u8; 32], position_index: u32)]
+pub struct ClaimPositionFees<'info> {
+    #[account(
+        seeds = [VAULT_SEED, vault_id.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_initialized,
+        constraint = vault.position_initialized,
+        constraint = !vault.paused @ FeeRouterError::VaultPaused
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [DISTRIBUTION_STATE_SEED, vault_id.as_ref()],
+        bump = distribution_state.bump
+    )]
+    pub distribution_state: Box<Account<'info, DistributionState>>,
+
+    /// Record of the position being claimed
+    #[account(
+        mut,
+        seeds = [FEE_POSITION_SEED, vault_id.as_ref(), &position_index.to_le_bytes()],
+        bump = fee_position_record.bump,
+        constraint = fee_position_record.vault == vault.key() @ FeeRouterError::InvalidPoolConfiguration
+    )]
+    pub fee_position_record: Box<Account<'info, FeePositionRecord>>,
+
+    /// Program-owned quote treasury ATA
+    #[account(
+        mut,
+        constraint = treasury_quote.key() == vault.treasury_quote,
+        constraint = treasury_quote.mint == vault.quote_mint @ FeeRouterError::InvalidQuoteMint
+    )]
+    pub treasury_quote: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Program-owned base treasury ATA (must remain zero)
+    #[account(
+        mut,
+        constraint = treasury_base.key() == vault.treasury_base
+    )]
+    pub treasury_base: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The fee position
+    /// CHECK: Validated against the position record
+    #[account(
+        mut,
+        constraint = fee_position.key() == fee_position_record.position
+    )]
+    pub fee_position: AccountInfo<'info>,
+
+    /// The position owner PDA
+    /// CHECK: PDA derivation
+    #[account(
+        seeds = [VAULT_SEED, vault_id.as_ref(), INVESTOR_FEE_POSITION_OWNER_SEED],
+        bump
+    )]
+    pub fee_position_owner: AccountInfo<'info>,
+
+    /// The DLMM pool the fee position belongs to
+    /// CHECK: Validated against the position record
+    #[account(
+        mut,
+        constraint = pool.key() == fee_position_record.pool
+    )]
+    pub pool: AccountInfo<'info>,
+
+    /// Pool's token X reserve
+    /// CHECK: Validated by DLMM program
+    #[account(mut)]
+    pub reserve_x: AccountInfo<'info>,
+
+    /// Pool's token Y reserve
+    /// CHECK: Validated by DLMM program
+    #[account(mut)]
+    pub reserve_y: AccountInfo<'info>,
+
+    /// Bin array covering the position's lower bin
+    /// CHECK: Validated by DLMM program
+    #[account(mut)]
+    pub bin_array_lower: AccountInfo<'info>,
+
+    /// Bin array covering the position's upper bin
+    /// CHECK: Validated by DLMM program
+    #[account(mut)]
+    pub bin_array_upper: AccountInfo<'info>,
+
+    /// Pool's token X mint
+    /// CHECK: Validated by DLMM program
+    pub token_x_mint: AccountInfo<'info>,
+
+    /// Pool's token Y mint
+    /// CHECK: Validated by DLMM program
+    pub token_y_mint: AccountInfo<'info>,
+
+    /// DLMM event authority PDA
+    /// CHECK: PDA derivation under the DLMM program
+    #[account(
+        seeds = [dlmm_integration::EVENT_AUTHORITY_SEED],
+        bump,
+        seeds::program = dlmm_program.key()
+    )]
+    pub event_authority: AccountInfo<'info>,
+
+    /// DLMM program for claiming fees
+    /// CHECK: Program ID validation
+    #[account(
+        constraint = dlmm_program.key() == DLMM_PROGRAM_ID
+    )]
+    pub dlmm_program: AccountInfo<'info>,
+
+    pub crank_operator: Signer<'info>,
+
+    /// Token program of the quote mint
+    #[account(
+        constraint = token_program.key() == *treasury_quote.to_account_info().owner @ FeeRouterError::InvalidQuoteMint
+    )]
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Token program of the base mint (may differ from the quote side's)
+    #[account(
+        constraint = base_token_program.key() == *treasury_base.to_account_info().owner @ FeeRouterError::InvalidPoolConfiguration
+    )]
+    pub base_token_program: Interface<'info, TokenInterface>,
+
+    /// SPL Memo program, only needed when the quote and base token programs differ
+    /// CHECK: Program ID validation
+    #[account(address = MEMO_PROGRAM_ID)]
+    pub memo_program: Option<AccountInfo<'info>>,
+}
+
+pub fn claim_position_fees(
+    ctx: Context<ClaimPositionFees>,
+    vault_id: [u8; 32],
+    position_index: u32,
+) -> Result<()> {
+    let current_ts = Clock::get()?.unix_timestamp;
+
+    // Positions are claimed for the upcoming day, never while one is being paid out
+    let distribution_state = &ctx.accounts.distribution_state;
+    require!(!distribution_state.is_day_open(), FeeRouterError::DayInProgress);
+    require!(
+        distribution_state.can_distribute(current_ts, &ctx.accounts.vault),
+        FeeRouterError::DistributionWindowNotReached
+    );
+
+    // Each position counts once towards the upcoming day. A round that has claimed nothing
+    // yet stays open so positions can be claimed again once they earn fees; otherwise a vault
+    // without `roll_over_empty_days` could never open another day
+    let claim_round = distribution_state.claim_round();
+    let already_claimed = ctx.accounts.fee_position_record.claimed_for_day == claim_round;
+    require!(
+        !already_claimed || distribution_state.pending_claimed_fees == 0,
+        FeeRouterError::PositionAlreadyClaimed
+    );
+
+    // Claim fees from the position via CPI and enforce quote-only
+    let claimed_amount = claim_fees_from_position(
+        ctx.accounts,
+        vault_id,
+        ctx.bumps.fee_position_owner,
+    )?;
+
+    let record = &mut ctx.accounts.fee_position_record;
+    record.claimed_for_day = claim_round;
+    record.total_claimed = record.total_claimed
+        .checked_add(claimed_amount)
+        .ok_or(FeeRouterError::MathOverflow)?;
+
+    let distribution_state = &mut ctx.accounts.distribution_state;
+    distribution_state.pending_claimed_fees = distribution_state.pending_claimed_fees
+        .checked_add(claimed_amount)
+        .ok_or(FeeRouterError::MathOverflow)?;
+    if !already_claimed {
+        distribution_state.positions_claimed += 1;
+    }
+
+    emit!(PositionFeesClaimed {
+        vault_id,
+        position_index,
+        position: record.position,
+        pool: record.pool,
+        amount: claimed_amount,
+        positions_claimed: distribution_state.positions_claimed,
+        pending_claimed_fees: distribution_state.pending_claimed_fees,
+        timestamp: current_ts,
+    });
+
+    Ok(())
+}
+
+fn claim_fees_from_position(
+    accounts: &mut ClaimPositionFees,
+    vault_id: [u8; 32],
+    fee_owner_bump: u8,
+) -> Result<u64> {
+    // Capture balances before
+    let base_before = accounts.treasury_base.amount;
+    let quote_before = accounts.treasury_quote.amount;
+
+    // Route claimed X/Y fees into the matching treasury, each under its own token program
+    let quote_side = (accounts.treasury_quote.to_account_info(), accounts.token_program.to_account_info());
+    let base_side = (accounts.treasury_base.to_account_info(), accounts.base_token_program.to_account_info());
+    let pool_state = dlmm_integration::deserialize_lb_pair(&accounts.pool)?;
+    let ((user_token_x, token_program_x), (user_token_y, token_program_y)) =
+        if pool_state.token_x_mint == accounts.vault.quote_mint {
+            (quote_side, base_side)
+        } else {
+            (base_side, quote_side)
+        };
+
+    let bump = [fee_owner_bump];
+    let signer = &[&[
+        VAULT_SEED,
+        &vault_id,
+        INVESTOR_FEE_POSITION_OWNER_SEED,
+        &bump,
+    ][..]];
+
+    // CPI failures abort the claim rather than being treated as zero fees. `claim_fee` takes a
+    // single token program; pairs mixing SPL Token and Token-2022 go through `claim_fee2`
+    if token_program_x.key() == token_program_y.key() {
+        dlmm_integration::cpi::claim_position_fees(
+            dlmm_integration::cpi::ClaimFee {
+                lb_pair: accounts.pool.to_account_info(),
+                position: accounts.fee_position.to_account_info(),
+                bin_array_lower: accounts.bin_array_lower.to_account_info(),
+                bin_array_upper: accounts.bin_array_upper.to_account_info(),
+                sender: accounts.fee_position_owner.to_account_info(),
+                reserve_x: accounts.reserve_x.to_account_info(),
+                reserve_y: accounts.reserve_y.to_account_info(),
+                user_token_x,
+                user_token_y,
+                token_x_mint: accounts.token_x_mint.to_account_info(),
+                token_y_mint: accounts.token_y_mint.to_account_info(),
+                token_program: token_program_x,
+                event_authority: accounts.event_authority.to_account_info(),
+                program: accounts.dlmm_program.to_account_info(),
+            },
+            signer,
+        )?;
+    } else {
+        let memo_program = accounts.memo_program
+            .as_ref()
+            .ok_or(FeeRouterError::MemoProgramRequired)?;
+        let mut bin_arrays = vec![accounts.bin_array_lower.to_account_info()];
+        if accounts.bin_array_upper.key() != accounts.bin_array_lower.key() {
+            bin_arrays.push(accounts.bin_array_upper.to_account_info());
+        }
+        dlmm_integration::cpi::claim_position_fees2(
+            dlmm_integration::cpi::ClaimFee2 {
+                lb_pair: accounts.pool.to_account_info(),
+                position: accounts.fee_position.to_account_info(),
+                sender: accounts.fee_position_owner.to_account_info(),
+                reserve_x: accounts.reserve_x.to_account_info(),
+                reserve_y: accounts.reserve_y.to_account_info(),
+                user_token_x,
+                user_token_y,
+                token_x_mint: accounts.token_x_mint.to_account_info(),
+                token_y_mint: accounts.token_y_mint.to_account_info(),
+                token_program_x,
+                token_program_y,
+                memo_program: memo_program.to_account_info(),
+                event_authority: accounts.event_authority.to_account_info(),
+                program: accounts.dlmm_program.to_account_info(),
+                bin_arrays,
+            },
+            accounts.fee_position_record.lower_bin_id,
+            accounts.fee_position_record.upper_bin_id,
+            signer,
+        )?;
+    }
+
+    // The CPI mutated the treasuries underneath the deserialized accounts; reload to see it
+    accounts.treasury_quote.reload()?;
+    accounts.treasury_base.reload()?;
+    let base_after = accounts.treasury_base.amount;
+    let quote_after = accounts.treasury_quote.amount;
+
+    // Enforce no base fees observed and base treasury did not increase
+    require!(base_before == 0, FeeRouterError::BaseFeesDetected);
+    require!(base_after == base_before, FeeRouterError::BaseFeesDetected);
+
+    quote_after
+        .checked_sub(quote_before)
+        .ok_or(FeeRouterError::MathOverflow.into())
+}
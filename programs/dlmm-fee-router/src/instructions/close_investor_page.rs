@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    constants::*,
+    errors::FeeRouterError,
+    events::{InvestorPageClosed, InvestorFeesClaimed},
+    state::{Vault, DistributionState, InvestorPage, InvestorRecord},
+    token_integration,
+    utils::{load_account, close_pda_account},
+};
+
+#[derive(Accounts)]
+#[instruction(vault_id: [u8; 32], page: u32)]
+pub struct CloseInvestorPage<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault_id.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_initialized,
+        constraint = vault.admin == admin.key() @ FeeRouterError::Unauthorized,
+        constraint = vault.paused @ FeeRouterError::VaultNotPaused
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [DISTRIBUTION_STATE_SEED, vault_id.as_ref()],
+        bump = distribution_state.bump
+    )]
+    pub distribution_state: Box<Account<'info, DistributionState>>,
+
+    /// Last investor page of the vault
+    #[account(
+        mut,
+        close = admin,
+        seeds = [INVESTOR_PAGE_SEED, vault_id.as_ref(), &page.to_le_bytes()],
+        bump = investor_page.bump,
+        constraint = investor_page.vault == vault.key() @ FeeRouterError::InvalidInvestorData
+    )]
+    pub investor_page: Box<Account<'info, InvestorPage>>,
+
+    /// Program-owned quote treasury ATA, paying out fees still owed to the page's investors
+    #[account(
+        mut,
+        constraint = treasury_quote.key() == vault.treasury_quote
+    )]
+    pub treasury_quote: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = quote_mint.key() == vault.quote_mint @ FeeRouterError::InvalidQuoteMint
+    )]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The treasury owner PDA
+    /// CHECK: PDA derivation
+    #[account(
+        seeds = [VAULT_SEED, vault_id.as_ref(), INVESTOR_FEE_POSITION_OWNER_SEED],
+        bump
+    )]
+    pub fee_position_owner: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    // Remaining accounts are, for every InvestorRecord PDA registered on the page, in page order,
+    // the record and the investor's quote token account (only used when fees are still owed)
+    // Format: [investor_record_0, investor_quote_account_0, investor_record_1, ...]
+}
+
+/// Remaining accounts supplied per registered investor: record, quote token account
+const ACCOUNTS_PER_INVESTOR: usize = 2;
+
+/// Accounts of a `close_investor_page` transaction besides the per-investor ones:
+/// the `CloseInvestorPage` accounts, this program and a compute budget instruction
+const CLOSE_PAGE_FIXED_ACCOUNTS: usize = 10;
+
+// A full page must be closed in a single transaction
+const _: () = assert!(
+    CLOSE_PAGE_FIXED_ACCOUNTS + MAX_INVESTORS_PER_PAGE * ACCOUNTS_PER_INVESTOR <= MAX_TX_ACCOUNT_LOCKS
+);
+
+pub fn close_investor_page<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseInvestorPage<'info>>,
+    vault_id: [u8; 32],
+    page: u32,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.distribution_state.is_day_open(),
+        FeeRouterError::DayInProgress
+    );
+
+    // Pages are closed from the last one down so the remaining pages stay contiguous
+    let vault = &mut ctx.accounts.vault;
+    require!(
+        page + 1 == vault.investor_page_count,
+        FeeRouterError::InvalidPageNumber
+    );
+
+    let investor_page = &ctx.accounts.investor_page;
+    require!(
+        ctx.remaining_accounts.len() == investor_page.investors.len() * ACCOUNTS_PER_INVESTOR,
+        FeeRouterError::InvalidInvestorData
+    );
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        VAULT_SEED,
+        vault_id.as_ref(),
+        INVESTOR_FEE_POSITION_OWNER_SEED,
+        &[ctx.bumps.fee_position_owner],
+    ]];
+    let admin = ctx.accounts.admin.to_account_info();
+    let current_ts = Clock::get()?.unix_timestamp;
+    let mut closed_allocation = 0u64;
+    for (registered, accounts) in investor_page.investors.iter()
+        .zip(ctx.remaining_accounts.chunks_exact(ACCOUNTS_PER_INVESTOR))
+    {
+        let (record_info, destination) = (&accounts[0], &accounts[1]);
+        require_keys_eq!(record_info.key(), *registered, FeeRouterError::InvalidInvestorData);
+        let record = load_account::<InvestorRecord>(record_info)?;
+        require_keys_eq!(record.vault, vault.key(), FeeRouterError::InvalidInvestorData);
+
+        // Fees still accrued to the investor are pushed to their quote token account,
+        // so an inactive investor cannot hold up the teardown
+        if record.fees_owed > 0 {
+            require_keys_eq!(
+                *destination.owner,
+                ctx.accounts.token_program.key(),
+                FeeRouterError::InvalidInvestorData
+            );
+            let quote_account = TokenAccount::try_deserialize(&mut &destination.try_borrow_data()?[..])?;
+            require_keys_eq!(quote_account.owner, record.investor, FeeRouterError::InvalidInvestorData);
+            require_keys_eq!(quote_account.mint, vault.quote_mint, FeeRouterError::InvalidQuoteMint);
+
+            let transfer_fee = token_integration::transfer_from_treasury(
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.treasury_quote.to_account_info(),
+                &ctx.accounts.quote_mint,
+                destination.clone(),
+                ctx.accounts.fee_position_owner.to_account_info(),
+                signer_seeds,
+                record.fees_owed,
+            )?;
+            let received = record.fees_owed - transfer_fee;
+
+            let distribution_state = &mut ctx.accounts.distribution_state;
+            distribution_state.fees_owed = distribution_state.fees_owed
+                .checked_sub(record.fees_owed)
+                .ok_or(FeeRouterError::MathOverflow)?;
+
+            emit!(InvestorFeesClaimed {
+                vault_id,
+                investor: record.investor,
+                destination: destination.key(),
+                amount: received,
+                transfer_fee,
+                total_fees_received: record.total_fees_received.saturating_add(received),
+                timestamp: current_ts,
+            });
+        }
+
+        closed_allocation = closed_allocation
+            .checked_add(record.initial_allocation)
+            .ok_or(FeeRouterError::MathOverflow)?;
+        close_pda_account(record_info, &admin)?;
+    }
+
+    let records_closed = investor_page.investors.len() as u32;
+    vault.investor_count = vault.investor_count
+        .checked_sub(records_closed)
+        .ok_or(FeeRouterError::MathOverflow)?;
+    vault.total_investor_allocation = vault.total_investor_allocation
+        .checked_sub(closed_allocation)
+        .ok_or(FeeRouterError::MathOverflow)?;
+    vault.investor_page_count = page;
+
+    emit!(InvestorPageClosed {
+        vault_id,
+        page,
+        records_closed,
+        investor_count: vault.investor_count,
+        total_investor_allocation: vault.total_investor_allocation,
+        timestamp: current_ts,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    constants::*,
+    errors::FeeRouterError,
+    events::VaultClosed,
+    state::{Vault, DistributionState, PageBitmap, FeePositionRecord},
+    utils::{load_account, close_pda_account},
+    token_integration,
+};
+
+#[derive(Accounts)]
+#[instruction(vault_id: [u8; 32])]
+pub struct CloseVault<'info> {
+    #[account(
+        mut,
+        close = admin,
+        seeds = [VAULT_SEED, vault_id.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_initialized,
+        constraint = vault.admin == admin.key() @ FeeRouterError::Unauthorized
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [DISTRIBUTION_STATE_SEED, vault_id.as_ref()],
+        bump = distribution_state.bump
+    )]
+    pub distribution_state: Box<Account<'info, DistributionState>>,
+
+    /// Program-owned quote treasury ATA
+    #[account(
+        mut,
+        constraint = treasury_quote.key() == vault.treasury_quote
+    )]
+    pub treasury_quote: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Program-owned base treasury ATA
+    #[account(
+        mut,
+        constraint = treasury_base.key() == vault.treasury_base
+    )]
+    pub treasury_base: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Creator's quote token account, receiving the swept treasury balance
+    #[account(
+        mut,
+        constraint = creator_quote_account.owner == vault.creator_wallet,
+        constraint = creator_quote_account.mint == vault.quote_mint @ FeeRouterError::InvalidQuoteMint
+    )]
+    pub creator_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Creator's base token account, receiving any base tokens sent to the base treasury
+    #[account(
+        mut,
+        constraint = creator_base_account.owner == vault.creator_wallet,
+        constraint = creator_base_account.mint == vault.base_mint @ FeeRouterError::InvalidPoolConfiguration
+    )]
+    pub creator_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Quote mint, also receiving fees withheld on the quote treasury
+    #[account(
+        mut,
+        mint::token_program = token_program,
+        constraint = quote_mint.key() == vault.quote_mint @ FeeRouterError::InvalidQuoteMint
+    )]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Base mint, needed to release fees withheld on the base treasury
+    #[account(
+        mut,
+        mint::token_program = base_token_program,
+        constraint = base_mint.key() == vault.base_mint @ FeeRouterError::InvalidPoolConfiguration
+    )]
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The treasury owner PDA
+    /// CHECK: PDA derivation
+    #[account(
+        seeds = [VAULT_SEED, vault_id.as_ref(), INVESTOR_FEE_POSITION_OWNER_SEED],
+        bump
+    )]
+    pub fee_position_owner: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Token program of the quote mint
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Token program of the base mint
+    pub base_token_program: Interface<'info, TokenInterface>,
+
+    // Remaining accounts are all of the vault's FeePositionRecord PDAs followed by all of its
+    // PageBitmap chunks, each in index order; every one of them is closed
+    // Format: [fee_position_record_0, ..., fee_position_record_n, page_bitmap_0, ..., page_bitmap_m]
+}
+
+pub fn close_vault<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseVault<'info>>,
+    vault_id: [u8; 32],
+) -> Result<()> {
+    let distribution_state = &ctx.accounts.distribution_state;
+    require!(!distribution_state.is_day_open(), FeeRouterError::DayInProgress);
+
+    // Investor pages and records are closed in batches beforehand
+    require!(
+        ctx.accounts.vault.investor_page_count == 0,
+        FeeRouterError::InvestorAccountsRemaining
+    );
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        VAULT_SEED,
+        vault_id.as_ref(),
+        INVESTOR_FEE_POSITION_OWNER_SEED,
+        &[ctx.bumps.fee_position_owner],
+    ]];
+
+    // Sweep policy: with no investors left, the carry-over and any other balance go to the creator
+    let swept = ctx.accounts.treasury_quote.amount;
+    let sweep_transfer_fee = if swept > 0 {
+        token_integration::transfer_from_treasury(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.treasury_quote.to_account_info(),
+            &ctx.accounts.quote_mint,
+            ctx.accounts.creator_quote_account.to_account_info(),
+            ctx.accounts.fee_position_owner.to_account_info(),
+            signer_seeds,
+            swept,
+        )?
+    } else {
+        0
+    };
+
+    // The base treasury never receives fees, but anyone can send it tokens; they go to the
+    // creator so they can't block the teardown
+    let base_swept = ctx.accounts.treasury_base.amount;
+    if base_swept > 0 {
+        token_integration::transfer_from_treasury(
+            ctx.accounts.base_token_program.to_account_info(),
+            ctx.accounts.treasury_base.to_account_info(),
+            &ctx.accounts.base_mint,
+            ctx.accounts.creator_base_account.to_account_info(),
+            ctx.accounts.fee_position_owner.to_account_info(),
+            signer_seeds,
+            base_swept,
+        )?;
+    }
+
+    // Token-2022 accounts holding withheld transfer fees cannot be closed until they are harvested
+    for (treasury, mint, token_program) in [
+        (&ctx.accounts.treasury_quote, &ctx.accounts.quote_mint, &ctx.accounts.token_program),
+        (&ctx.accounts.treasury_base, &ctx.accounts.base_mint, &ctx.accounts.base_token_program),
+    ] {
+        token_integration::harvest_withheld_fees(
+            token_program.to_account_info(),
+            mint,
+            treasury.to_account_info(),
+        )?;
+        token_interface::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: treasury.to_account_info(),
+                destination: ctx.accounts.admin.to_account_info(),
+                authority: ctx.accounts.fee_position_owner.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    // Every position record and bitmap chunk must be closed, or its rent is lost and the
+    // vault_id can never be initialized again
+    let position_count = ctx.accounts.vault.position_count as usize;
+    let bitmap_chunks = distribution_state.bitmap_chunks as usize;
+    require!(
+        ctx.remaining_accounts.len() == position_count + bitmap_chunks,
+        FeeRouterError::InvalidInvestorData
+    );
+    let (position_records, page_bitmaps) = ctx.remaining_accounts.split_at(position_count);
+
+    let admin = ctx.accounts.admin.to_account_info();
+    for (index, info) in position_records.iter().enumerate() {
+        let record = load_account::<FeePositionRecord>(info)?;
+        require_keys_eq!(record.vault, ctx.accounts.vault.key(), FeeRouterError::InvalidInvestorData);
+        require!(record.index as usize == index, FeeRouterError::InvalidInvestorData);
+        close_pda_account(info, &admin)?;
+    }
+    for (chunk, info) in page_bitmaps.iter().enumerate() {
+        let bitmap = load_account::<PageBitmap>(info)?;
+        require_keys_eq!(bitmap.vault, ctx.accounts.vault.key(), FeeRouterError::InvalidInvestorData);
+        require!(bitmap.chunk as usize == chunk, FeeRouterError::InvalidInvestorData);
+        close_pda_account(info, &admin)?;
+    }
+
+    emit!(VaultClosed {
+        vault_id,
+        admin: ctx.accounts.admin.key(),
+        swept_to_creator: swept - sweep_transfer_fee,
+        sweep_transfer_fee,
+        base_swept_to_creator: base_swept,
+        carry_over_forfeited: distribution_state.carry_over,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
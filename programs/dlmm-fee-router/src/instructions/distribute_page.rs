@@ -0,0 +1,583 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{self, AssociatedToken, Create};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    constants::*,
+    errors::FeeRouterError,
+    events::{InvestorPayoutPage, CreatorPayoutDayClosed, InvestorPayout, InvestorPayoutCarriedForward},
+    state::{Vault, DistributionState, InvestorPage, InvestorRecord, PageBitmap},
+    token_integration,
+    instructions::snapshot_investor_page::read_investor_lock,
+    utils::{create_pda_account, load_account, store_account, mul_div},
+};
+
+#[derive(Accounts)]
+#[instruction(vault_id: [u8; 32], page: u32, is_final_page: bool, create_missing_atas: bool)]
+pub struct DistributePage<'info> {
+    #[account(
+        seeds = [VAULT_SEED, vault_id.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_initialized,
+        constraint = vault.position_initialized,
+        constraint = !vault.paused @ FeeRouterError::VaultPaused
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+    
+    #[account(
+        mut,
+        seeds = [DISTRIBUTION_STATE_SEED, vault_id.as_ref()],
+        bump = distribution_state.bump
+    )]
+    pub distribution_state: Box<Account<'info, DistributionState>>,
+    
+    /// Investor page data for current page
+    #[account(
+        seeds = [INVESTOR_PAGE_SEED, vault_id.as_ref(), &page.to_le_bytes()],
+        bump = investor_page.bump,
+        constraint = investor_page.vault == vault.key() @ FeeRouterError::InvalidInvestorData
+    )]
+    pub investor_page: Box<Account<'info, InvestorPage>>,
+    
+    /// Processed-page bitmap chunk covering `page` (created on first use)
+    /// CHECK: PDA derivation; created and deserialized in instruction
+    #[account(
+        mut,
+        seeds = [
+            PAGE_BITMAP_SEED,
+            vault_id.as_ref(),
+            &PageBitmap::chunk_for(page).to_le_bytes()
+        ],
+        bump
+    )]
+    pub page_bitmap: AccountInfo<'info>,
+    
+    /// Program-owned quote treasury ATA
+    #[account(
+        mut,
+        constraint = treasury_quote.key() == vault.treasury_quote,
+        constraint = treasury_quote.mint == vault.quote_mint @ FeeRouterError::InvalidQuoteMint
+    )]
+    pub treasury_quote: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Creator's quote token account
+    #[account(
+        mut,
+        constraint = creator_quote_account.owner == vault.creator_wallet,
+        constraint = creator_quote_account.mint == vault.quote_mint @ FeeRouterError::InvalidQuoteMint
+    )]
+    pub creator_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// The treasury owner PDA
+    /// CHECK: PDA derivation
+    #[account(
+        seeds = [VAULT_SEED, vault_id.as_ref(), INVESTOR_FEE_POSITION_OWNER_SEED],
+        bump
+    )]
+    pub fee_position_owner: AccountInfo<'info>,
+
+    /// Streamflow program for reading vesting data
+    /// CHECK: Program ID validation
+    #[account(
+        constraint = streamflow_program.key() == STREAMFLOW_PROGRAM_ID
+    )]
+    pub streamflow_program: AccountInfo<'info>,
+    
+    /// Quote mint; payouts are `transfer_checked` against its decimals
+    #[account(
+        constraint = quote_mint.key() == vault.quote_mint @ FeeRouterError::InvalidQuoteMint
+    )]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(mut)]
+    pub crank_operator: Signer<'info>,
+    
+    /// Crank's quote token account, reimbursed for investor ATAs it creates (optional)
+    #[account(
+        mut,
+        constraint = crank_quote_account.owner == crank_operator.key() @ FeeRouterError::Unauthorized,
+        constraint = crank_quote_account.mint == vault.quote_mint @ FeeRouterError::InvalidQuoteMint
+    )]
+    pub crank_quote_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+    
+    // Remaining accounts are, for every investor registered on the page, in page order:
+    // Format: [investor_record_0, investor_ata_0, stream_0, investor_record_1, ...]
+    // With `create_missing_atas` the investor wallet follows the ATA:
+    // [investor_record_0, investor_ata_0, investor_wallet_0, stream_0, ...]
+    // In accrual mode the ATA is omitted: [investor_record_0, stream_0, ...]
+}
+
+/// Remaining accounts supplied per registered investor: record, quote ATA (unless accruing),
+/// investor wallet (when creating missing ATAs), stream
+fn accounts_per_investor(vault: &Vault, create_missing_atas: bool) -> usize {
+    match (vault.accrual_mode, create_missing_atas) {
+        (true, _) => 2,
+        (false, false) => 3,
+        (false, true) => 4,
+    }
+}
+
+/// Accounts of a `distribute_page` transaction besides the per-investor ones:
+/// the `DistributePage` accounts, this program and a compute budget instruction
+const DISTRIBUTE_PAGE_FIXED_ACCOUNTS: usize = 17;
+
+// A full page creating missing ATAs must fit within a transaction's account locks
+const _: () = assert!(
+    DISTRIBUTE_PAGE_FIXED_ACCOUNTS + MAX_INVESTORS_PER_PAGE * 4 <= MAX_TX_ACCOUNT_LOCKS
+);
+
+pub fn distribute_page<'info>(
+    ctx: Context<'_, '_, '_, 'info, DistributePage<'info>>,
+    vault_id: [u8; 32],
+    page: u32,
+    is_final_page: bool,
+    create_missing_atas: bool,
+) -> Result<()> {
+    let current_ts = ctx.accounts.clock.unix_timestamp;
+    
+    // The day is opened by `claim_day_fees`; pages only pay out its frozen pool
+    require!(
+        ctx.accounts.distribution_state.is_day_open(),
+        FeeRouterError::DayNotStarted
+    );
+
+    // Validate page number
+    require!(
+        page == ctx.accounts.distribution_state.current_page
+            && page < ctx.accounts.distribution_state.day_page_count,
+        FeeRouterError::InvalidPageNumber
+    );
+
+    // Per-page idempotency: a page can only be processed once per day
+    mark_page_processed(
+        &ctx.accounts.crank_operator,
+        &ctx.accounts.page_bitmap,
+        &ctx.accounts.system_program,
+        &mut ctx.accounts.distribution_state,
+        ctx.bumps.page_bitmap,
+        vault_id,
+        page,
+    )?;
+    
+    // Accounts must cover exactly the investors registered on this page, in order
+    let accounts_per_investor = accounts_per_investor(&ctx.accounts.vault, create_missing_atas);
+    require!(
+        ctx.remaining_accounts.len() == ctx.accounts.investor_page.investors.len() * accounts_per_investor,
+        FeeRouterError::InvalidInvestorData
+    );
+    
+    // Optionally create investor quote ATAs that don't exist yet; the crank pays the rent
+    let atas_created = if create_missing_atas && !ctx.accounts.vault.accrual_mode {
+        create_missing_investor_atas(ctx.accounts, ctx.remaining_accounts)?
+    } else {
+        0
+    };
+    
+    let vault = &ctx.accounts.vault;
+    let distribution_state = &mut ctx.accounts.distribution_state;
+    
+    // The page must have been part of the snapshot the pool was frozen against
+    let investor_page = &ctx.accounts.investor_page;
+    require!(
+        investor_page.snapshot_ts == distribution_state.snapshot_ts,
+        FeeRouterError::SnapshotIncomplete
+    );
+    
+    // Calculate investor distributions for this page
+    let (live_locked, mut investor_payouts) = calculate_investor_payouts(
+        vault,
+        investor_page,
+        ctx.remaining_accounts,
+        accounts_per_investor,
+        distribution_state.snapshot_ts,
+        &ctx.accounts.token_program.key(),
+    )?;
+    
+    // The page's share of the pool follows its snapshotted share of the day's locked total
+    let page_share = distribution_state.allocate_page_share(investor_page.total_locked)?;
+    
+    // Never split more than the page's locked total, even if a stream was topped up since
+    let page_locked = investor_page.total_locked.max(live_locked);
+    
+    // Compute exact pro-rata payouts and rounding remainder
+    let mut allocated_total = 0u64;
+    for p in investor_payouts.iter_mut() {
+        p.amount = mul_div(page_share, p.locked_amount, page_locked)?;
+        allocated_total = allocated_total.saturating_add(p.amount);
+    }
+    let rounding_remainder = page_share.saturating_sub(allocated_total);
+    distribution_state.carry_forward(rounding_remainder)?;
+    
+    // Distribute to investors
+    let mut total_distributed = 0u64;
+    let quote_mint_info = ctx.accounts.quote_mint.to_account_info();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        VAULT_SEED,
+        vault_id.as_ref(),
+        INVESTOR_FEE_POSITION_OWNER_SEED,
+        &[ctx.bumps.fee_position_owner],
+    ]];
+    for (i, payout) in investor_payouts.iter().enumerate() {
+        // Dust only matters for transfers (judged on what arrives after the mint's transfer fee);
+        // accrued credits are claimed in one go later
+        if !vault.accrual_mode
+            && token_integration::net_of_transfer_fee(&quote_mint_info, payout.amount)?
+                < vault.min_payout_lamports
+        {
+            // Add to carry-over
+            distribution_state.carry_forward(payout.amount)?;
+            continue;
+        }
+        
+        // Check daily cap (scaled to the distribution period) if applicable
+        if let Some(cap) = vault.period_cap_lamports() {
+            if distribution_state.daily_distributed + payout.amount > cap {
+                distribution_state.carry_forward(payout.amount)?;
+                continue;
+            }
+        }
+        
+        // An investor whose quote ATA is missing, closed or frozen can't be paid today; their
+        // payout stays with investors instead of failing the whole page
+        if !payout.payable {
+            distribution_state.carry_forward(payout.amount)?;
+            emit!(InvestorPayoutCarriedForward {
+                vault_id,
+                investor: payout.investor,
+                amount: payout.amount,
+                distribution_day: distribution_state.current_day,
+                timestamp: current_ts,
+            });
+            continue;
+        }
+        
+        require!(
+            payout.amount <= distribution_state.day_remaining()?,
+            FeeRouterError::DistributionExceedsInflow
+        );
+        
+        let transfer_fee = if vault.accrual_mode {
+            // Credit the record; the investor withdraws with `claim_investor_fees`
+            let record_info = &ctx.remaining_accounts[i * accounts_per_investor];
+            let mut record = load_account::<InvestorRecord>(record_info)?;
+            record.fees_owed = record.fees_owed
+                .checked_add(payout.amount)
+                .ok_or(FeeRouterError::MathOverflow)?;
+            record.last_distribution_ts = current_ts;
+            store_account(&record, record_info)?;
+            
+            distribution_state.fees_owed = distribution_state.fees_owed
+                .checked_add(payout.amount)
+                .ok_or(FeeRouterError::MathOverflow)?;
+            // Any transfer fee is withheld when the credit is claimed
+            0
+        } else {
+            // Transfer tokens to investor; the mint may withhold a transfer fee on the way
+            let investor_ata = &ctx.remaining_accounts[i * accounts_per_investor + 1];
+            token_integration::transfer_from_treasury(
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.treasury_quote.to_account_info(),
+                &ctx.accounts.quote_mint,
+                investor_ata.to_account_info(),
+                ctx.accounts.fee_position_owner.to_account_info(),
+                signer_seeds,
+                payout.amount,
+            )?
+        };
+        
+        total_distributed += payout.amount;
+        distribution_state.daily_distributed += payout.amount;
+        
+        emit!(InvestorPayout {
+            vault_id,
+            investor: payout.investor,
+            amount: payout.amount - transfer_fee,
+            transfer_fee,
+            locked_amount: payout.locked_amount,
+            weight: payout.weight,
+            accrued: vault.accrual_mode,
+            timestamp: current_ts,
+        });
+    }
+    
+    distribution_state.day_investor_total += total_distributed;
+    distribution_state.day_remaining()?;
+    
+    // Reimburse the crank for the ATAs it created, out of the creator share only
+    let mut ata_rent_reimbursed = 0u64;
+    if let Some(crank_quote_account) = &ctx.accounts.crank_quote_account {
+        ata_rent_reimbursed = (atas_created as u64)
+            .saturating_mul(vault.ata_rent_reimbursement)
+            .min(distribution_state.creator_share_unreimbursed());
+        if ata_rent_reimbursed > 0 {
+            distribution_state.reimburse_ata_rent(ata_rent_reimbursed)?;
+            token_integration::transfer_from_treasury(
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.treasury_quote.to_account_info(),
+                &ctx.accounts.quote_mint,
+                crank_quote_account.to_account_info(),
+                ctx.accounts.fee_position_owner.to_account_info(),
+                signer_seeds,
+                ata_rent_reimbursed,
+            )?;
+        }
+    }
+    
+    emit!(InvestorPayoutPage {
+        vault_id,
+        page,
+        total_payout: total_distributed,
+        investor_count: investor_payouts.len() as u32,
+        atas_created,
+        ata_rent_reimbursed,
+        daily_distributed_after: distribution_state.daily_distributed,
+        timestamp: current_ts,
+    });
+    
+    // The day closes once every page it opened with is done
+    distribution_state.pages_processed += 1;
+    let closes_day = distribution_state.all_pages_done();
+    require!(!is_final_page || closes_day, FeeRouterError::PagesRemaining);
+    
+    // If final page, distribute remainder to creator
+    if closes_day {
+        close_distribution_day(
+            distribution_state,
+            CreatorPayoutAccounts {
+                token_program: ctx.accounts.token_program.to_account_info(),
+                treasury_quote: ctx.accounts.treasury_quote.to_account_info(),
+                quote_mint: &ctx.accounts.quote_mint,
+                creator_quote_account: ctx.accounts.creator_quote_account.to_account_info(),
+                fee_position_owner: ctx.accounts.fee_position_owner.to_account_info(),
+            },
+            signer_seeds,
+            vault_id,
+            current_ts,
+        )?;
+    } else {
+        // Move to next page and advance pagination cursor for idempotency
+        distribution_state.current_page += 1;
+        distribution_state.page_cursor = distribution_state.page_cursor.saturating_add(1);
+    }
+    
+    Ok(())
+}
+
+#[derive(Debug)]
+struct InvestorPayoutInfo {
+    investor: Pubkey,
+    amount: u64,
+    locked_amount: u64,
+    weight: u64,
+    /// Whether the investor's quote ATA can receive a transfer (always true when accruing)
+    payable: bool,
+}
+
+fn calculate_investor_payouts(
+    vault: &Vault,
+    investor_page: &InvestorPage,
+    remaining_accounts: &[AccountInfo],
+    accounts_per_investor: usize,
+    snapshot_ts: i64,
+    token_program: &Pubkey,
+) -> Result<(u64, Vec<InvestorPayoutInfo>)> {
+    let mut total_locked = 0u64;
+    let mut payouts = Vec::with_capacity(investor_page.investors.len());
+
+    for (registered, accounts) in investor_page.investors.iter()
+        .zip(remaining_accounts.chunks_exact(accounts_per_investor))
+    {
+        let (record_acc, stream_acc) = (&accounts[0], &accounts[accounts_per_investor - 1]);
+        let (record, locked_amount) = read_investor_lock(
+            vault,
+            investor_page,
+            registered,
+            record_acc,
+            stream_acc,
+            snapshot_ts,
+        )?;
+
+        // Payouts may only go to the registered investor's quote token account. A missing
+        // account must be the investor's ATA, so the crank can't withhold a payout by
+        // passing some other empty account
+        let payable = if vault.accrual_mode {
+            true
+        } else if accounts[1].data_is_empty() {
+            let expected_ata = associated_token::get_associated_token_address_with_program_id(
+                &record.investor,
+                &vault.quote_mint,
+                token_program,
+            );
+            require_keys_eq!(accounts[1].key(), expected_ata, FeeRouterError::InvalidInvestorData);
+            false
+        } else {
+            let ata = read_token_account(&accounts[1], token_program)?;
+            require_keys_eq!(ata.owner, record.investor, FeeRouterError::InvalidInvestorData);
+            require_keys_eq!(ata.mint, vault.quote_mint, FeeRouterError::InvalidQuoteMint);
+            !ata.is_frozen()
+        };
+
+        total_locked = total_locked.saturating_add(locked_amount);
+
+        payouts.push(InvestorPayoutInfo {
+            investor: record.investor,
+            amount: 0, // computed later
+            locked_amount,
+            weight: locked_amount,
+            payable,
+        });
+    }
+
+    Ok((total_locked, payouts))
+}
+
+/// Create the quote ATA of every investor on the page that doesn't have one yet.
+/// Returns how many were created.
+fn create_missing_investor_atas<'info>(
+    accounts: &DistributePage<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<u32> {
+    let mut created = 0u32;
+    for (registered, investor_accounts) in accounts.investor_page.investors.iter()
+        .zip(remaining_accounts.chunks_exact(4))
+    {
+        let (record_acc, ata, wallet) = (&investor_accounts[0], &investor_accounts[1], &investor_accounts[2]);
+        if !ata.data_is_empty() {
+            continue;
+        }
+
+        require_keys_eq!(record_acc.key(), *registered, FeeRouterError::InvalidInvestorData);
+        let record = load_account::<InvestorRecord>(record_acc)?;
+        require_keys_eq!(wallet.key(), record.investor, FeeRouterError::InvalidInvestorData);
+
+        // The associated token program checks `ata` is the wallet's ATA for the quote mint
+        associated_token::create_idempotent(CpiContext::new(
+            accounts.associated_token_program.to_account_info(),
+            Create {
+                payer: accounts.crank_operator.to_account_info(),
+                associated_token: ata.clone(),
+                authority: wallet.clone(),
+                mint: accounts.quote_mint.to_account_info(),
+                system_program: accounts.system_program.to_account_info(),
+                token_program: accounts.token_program.to_account_info(),
+            },
+        ))?;
+        created += 1;
+    }
+    Ok(created)
+}
+
+/// Accounts that pay the creator's share out of the quote treasury
+pub(crate) struct CreatorPayoutAccounts<'a, 'info> {
+    pub token_program: AccountInfo<'info>,
+    pub treasury_quote: AccountInfo<'info>,
+    pub quote_mint: &'a InterfaceAccount<'info, Mint>,
+    pub creator_quote_account: AccountInfo<'info>,
+    pub fee_position_owner: AccountInfo<'info>,
+}
+
+/// Settle a day once its last page is done: the pool left unassigned stays with investors,
+/// the creator is paid what remains and the day is closed
+pub(crate) fn close_distribution_day(
+    distribution_state: &mut DistributionState,
+    accounts: CreatorPayoutAccounts,
+    signer_seeds: &[&[&[u8]]],
+    vault_id: [u8; 32],
+    current_ts: i64,
+) -> Result<()> {
+    // Investor pool left unassigned by page rounding stays with investors
+    let unallocated_pool = distribution_state.day_investor_pool
+        .saturating_sub(distribution_state.day_pool_allocated);
+    distribution_state.carry_forward(unallocated_pool)?;
+    distribution_state.day_pool_allocated = distribution_state.day_investor_pool;
+
+    // With nothing locked anywhere there is no investor left to receive the carry-over
+    let forfeited_to_creator = if distribution_state.day_total_locked == 0 {
+        distribution_state.forfeit_carry_over()
+    } else {
+        0
+    };
+
+    // Creator receives what was neither paid to nor reserved for investors
+    let creator_payout = distribution_state.day_remaining()?;
+
+    let creator_transfer_fee = if creator_payout > 0 {
+        token_integration::transfer_from_treasury(
+            accounts.token_program,
+            accounts.treasury_quote,
+            accounts.quote_mint,
+            accounts.creator_quote_account,
+            accounts.fee_position_owner,
+            signer_seeds,
+            creator_payout,
+        )?
+    } else {
+        0
+    };
+
+    distribution_state.close_day();
+
+    emit!(CreatorPayoutDayClosed {
+        vault_id,
+        creator_payout: creator_payout - creator_transfer_fee,
+        creator_transfer_fee,
+        forfeited_to_creator,
+        total_distributed_to_investors: distribution_state.day_investor_total,
+        carry_over: distribution_state.carry_over,
+        distribution_day: distribution_state.current_day,
+        timestamp: current_ts,
+    });
+
+    Ok(())
+}
+
+/// Set the page's bit for the open day in its bitmap chunk, creating the chunk on first use.
+/// Pages are processed in order, so chunks are created in order and counted in `bitmap_chunks`.
+pub(crate) fn mark_page_processed<'info>(
+    payer: &Signer<'info>,
+    bitmap_info: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    distribution_state: &mut DistributionState,
+    bitmap_bump: u8,
+    vault_id: [u8; 32],
+    page: u32,
+) -> Result<()> {
+    let chunk = PageBitmap::chunk_for(page);
+    let (vault, day) = (distribution_state.vault, distribution_state.current_day);
+
+    let mut bitmap = if bitmap_info.data_is_empty() {
+        create_pda_account(
+            payer,
+            bitmap_info,
+            system_program,
+            PageBitmap::LEN,
+            &[PAGE_BITMAP_SEED, vault_id.as_ref(), &chunk.to_le_bytes(), &[bitmap_bump]],
+        )?;
+        distribution_state.bitmap_chunks += 1;
+        PageBitmap {
+            vault,
+            chunk,
+            day,
+            bits: [0u8; 128],
+            bump: bitmap_bump,
+        }
+    } else {
+        load_account::<PageBitmap>(bitmap_info)?
+    };
+    require_keys_eq!(bitmap.vault, vault, FeeRouterError::InvalidInvestorData);
+
+    bitmap.mark_page_done(day, page)?;
+    store_account(&bitmap, bitmap_info)
+}
+
+/// Deserialize a token account owned by the vault's token program (SPL Token or Token-2022)
+fn read_token_account(token_account: &AccountInfo, token_program: &Pubkey) -> Result<TokenAccount> {
+    require_keys_eq!(*token_account.owner, *token_program, FeeRouterError::InvalidInvestorData);
+    let data = token_account.try_borrow_data()?;
+    TokenAccount::try_deserialize(&mut &data[..])
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::FeeRouterError,
+    events::VaultConfigUpdated,
+    state::{Vault, DistributionState},
+};
+
+#[derive(Accounts)]
+#[instruction(vault_id: [u8; 32])]
+pub struct ExecuteVaultConfig<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault_id.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_initialized
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    #[account(
+        seeds = [DISTRIBUTION_STATE_SEED, vault_id.as_ref()],
+        bump = distribution_state.bump
+    )]
+    pub distribution_state: Account<'info, DistributionState>,
+    
+    pub executor: Signer<'info>,
+}
+
+pub fn execute_vault_config(
+    ctx: Context<ExecuteVaultConfig>,
+    vault_id: [u8; 32],
+) -> Result<()> {
+    let current_ts = Clock::get()?.unix_timestamp;
+    let vault = &mut ctx.accounts.vault;
+    let pending = vault.pending_config.ok_or(FeeRouterError::NoPendingConfigChange)?;
+    
+    require!(
+        current_ts >= pending.effective_ts,
+        FeeRouterError::ConfigTimelockNotElapsed
+    );
+    
+    // Pages of an open day must all be paid under the parameters the day started with
+    require!(
+        !ctx.accounts.distribution_state.is_day_open(),
+        FeeRouterError::DayInProgress
+    );
+    
+    let old_config = vault.config();
+    vault.apply_config(&pending.config);
+    vault.pending_config = None;
+    
+    emit!(VaultConfigUpdated {
+        vault_id,
+        executor: ctx.accounts.executor.key(),
+        old_config,
+        new_config: pending.config,
+        timestamp: current_ts,
+    });
+    
+    Ok(())
+}
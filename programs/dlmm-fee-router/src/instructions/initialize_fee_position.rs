@@ -1,12 +1,12 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, Mint, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenInterface};
 
 use crate::{
     constants::*,
     errors::FeeRouterError,
     events::HonoraryPositionInitialized,
-    state::Vault,
-    dlmm_integration::{deserialize_lb_pair, calculate_quote_only_ticks},
+    state::{Vault, FeePositionRecord},
+    dlmm_integration::{self, deserialize_lb_pair, calculate_quote_only_ticks},
 };
 
 /// External DLMM accounts - these would be from the Meteora DLMM program
@@ -19,12 +19,26 @@ pub struct InitializeFeePosition<'info> {
         seeds = [VAULT_SEED, vault_id.as_ref()],
         bump = vault.bump,
         constraint = vault.is_initialized,
-        constraint = !vault.position_initialized @ FeeRouterError::VaultAlreadyInitialized
+        constraint = vault.admin == authority.key() @ FeeRouterError::Unauthorized,
+        constraint = !vault.paused @ FeeRouterError::VaultPaused
     )]
     pub vault: Account<'info, Vault>,
     
+    /// Record of the new position, at the next free position index
+    #[account(
+        init,
+        payer = authority,
+        space = FeePositionRecord::LEN,
+        seeds = [FEE_POSITION_SEED, vault_id.as_ref(), &vault.position_count.to_le_bytes()],
+        bump
+    )]
+    pub fee_position_record: Account<'info, FeePositionRecord>,
+    
     /// The DLMM pool account
-    /// CHECK: Validated against DLMM program
+    /// CHECK: Owner checked against DLMM program, mints validated in instruction
+    #[account(
+        owner = DLMM_PROGRAM_ID @ FeeRouterError::InvalidPoolConfiguration
+    )]
     pub pool: AccountInfo<'info>,
     
     /// The position owner PDA
@@ -35,10 +49,9 @@ pub struct InitializeFeePosition<'info> {
     )]
     pub fee_position_owner: AccountInfo<'info>,
     
-    /// The new position account to be created
-    /// CHECK: Will be created by DLMM program
+    /// The new position account to be created (fresh keypair, signs for its creation)
     #[account(mut)]
-    pub fee_position: AccountInfo<'info>,
+    pub fee_position: Signer<'info>,
     
     /// Pool's token X vault
     /// CHECK: Validated by DLMM program
@@ -49,13 +62,25 @@ pub struct InitializeFeePosition<'info> {
     pub token_y_vault: AccountInfo<'info>,
     
     /// Token X mint
-    pub token_x_mint: Account<'info, Mint>,
+    pub token_x_mint: InterfaceAccount<'info, Mint>,
     
     /// Token Y mint
-    pub token_y_mint: Account<'info, Mint>,
+    pub token_y_mint: InterfaceAccount<'info, Mint>,
     
     /// Quote mint (must match either X or Y)
-    pub quote_mint: Account<'info, Mint>,
+    #[account(
+        constraint = quote_mint.key() == vault.quote_mint @ FeeRouterError::InvalidQuoteMint
+    )]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+    
+    /// DLMM event authority PDA
+    /// CHECK: PDA derivation under the DLMM program
+    #[account(
+        seeds = [dlmm_integration::EVENT_AUTHORITY_SEED],
+        bump,
+        seeds::program = dlmm_program.key()
+    )]
+    pub event_authority: AccountInfo<'info>,
     
     /// DLMM program
     /// CHECK: Program ID validation
@@ -68,7 +93,7 @@ pub struct InitializeFeePosition<'info> {
     pub authority: Signer<'info>,
     
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub rent: Sysvar<'info, Rent>,
 }
 
@@ -91,45 +116,74 @@ pub fn initialize_fee_position(
         FeeRouterError::InvalidQuoteMint
     );
     
-    // Preflight: parse DLMM pool and compute quote-only tick range
+    // Preflight: parse DLMM pool and ensure the supplied mints are the pool's mints
     let pool_state = deserialize_lb_pair(&ctx.accounts.pool)?;
+    require!(
+        pool_state.token_x_mint == ctx.accounts.token_x_mint.key()
+            && pool_state.token_y_mint == ctx.accounts.token_y_mint.key(),
+        FeeRouterError::InvalidPoolConfiguration
+    );
+    
+    // The non-quote side must be the base mint the treasuries were created for
+    let base_mint = if is_x_quote {
+        ctx.accounts.token_y_mint.key()
+    } else {
+        ctx.accounts.token_x_mint.key()
+    };
+    require!(
+        base_mint == vault.base_mint,
+        FeeRouterError::InvalidPoolConfiguration
+    );
+    
+    // Compute quote-only bin range
     let (tick_lower, tick_upper) = calculate_quote_only_ticks(&pool_state, &ctx.accounts.quote_mint.key())?;
     
-    // Example of what the CPI would look like:
-    // let cpi_accounts = dlmm::CreatePosition {
-    //     pool: ctx.accounts.pool.to_account_info(),
-    //     position: ctx.accounts.fee_position.to_account_info(),
-    //     owner: ctx.accounts.fee_position_owner.to_account_info(),
-    //     ...
-    // };
-    // let cpi_program = ctx.accounts.dlmm_program.to_account_info();
-    // let signer_seeds = &[
-    //     VAULT_SEED,
-    //     vault_id.as_ref(),
-    //     FEE_POSITION_OWNER_SEED,
-    //     &[ctx.bumps.fee_position_owner],
-    // ];
-    // dlmm::create_position(
-    //     CpiContext::new_with_signer(cpi_program, cpi_accounts, &[signer_seeds]),
-    //     tick_lower,
-    //     tick_upper,
-    //     liquidity_amount, // 0 for honorary position
-    // )?;
-    
-    // TODO: Perform DLMM CPI call to initialize position with 0 liquidity using computed ticks
-    // dlmm_integration::cpi::create_honorary_position(...)
+    // Create the zero-liquidity position, owned and signed for by the fee position owner PDA
+    dlmm_integration::cpi::create_honorary_position(
+        dlmm_integration::cpi::InitializePosition {
+            payer: ctx.accounts.authority.to_account_info(),
+            position: ctx.accounts.fee_position.to_account_info(),
+            lb_pair: ctx.accounts.pool.to_account_info(),
+            owner: ctx.accounts.fee_position_owner.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+            event_authority: ctx.accounts.event_authority.to_account_info(),
+            program: ctx.accounts.dlmm_program.to_account_info(),
+        },
+        tick_lower,
+        tick_upper - tick_lower + 1,
+        &[&[
+            VAULT_SEED,
+            vault_id.as_ref(),
+            INVESTOR_FEE_POSITION_OWNER_SEED,
+            &[ctx.bumps.fee_position_owner],
+        ]],
+    )?;
 
-    // Update vault state
-    vault.pool = ctx.accounts.pool.key();
-    vault.fee_position = ctx.accounts.fee_position.key();
+    // Register the position; fees are claimed from every registered position each day
+    let position_index = vault.position_count;
+    let record = &mut ctx.accounts.fee_position_record;
+    record.vault = vault.key();
+    record.index = position_index;
+    record.position = ctx.accounts.fee_position.key();
+    record.pool = ctx.accounts.pool.key();
+    record.lower_bin_id = tick_lower;
+    record.upper_bin_id = tick_upper;
+    record.claimed_for_day = 0;
+    record.total_claimed = 0;
+    record.bump = ctx.bumps.fee_position_record;
+    
+    vault.position_count += 1;
     vault.position_initialized = true;
-    vault.quote_mint = ctx.accounts.quote_mint.key();
     
     emit!(HonoraryPositionInitialized {
         vault_id,
+        position_index,
         position_pubkey: ctx.accounts.fee_position.key(),
         pool_pubkey: ctx.accounts.pool.key(),
         quote_mint: ctx.accounts.quote_mint.key(),
+        lower_bin_id: tick_lower,
+        upper_bin_id: tick_upper,
         timestamp: Clock::get()?.unix_timestamp,
     });
     
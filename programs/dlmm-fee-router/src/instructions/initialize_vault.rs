@@ -1,12 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{Token, Mint, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
     constants::*,
     errors::FeeRouterError,
     events::VaultInitialized,
-    state::{Vault, DistributionState},
+    state::{Vault, DistributionState, EpochConfig, InitializeVaultParams},
 };
 
 #[derive(Accounts)]
@@ -30,11 +30,13 @@ pub struct InitializeVault<'info> {
     )]
     pub distribution_state: Account<'info, DistributionState>,
     
-    /// Quote mint (usually USDC)
-    pub quote_mint: Account<'info, Mint>,
+    /// Quote mint (usually USDC); SPL Token or Token-2022, including transfer-fee mints
+    #[account(mint::token_program = token_program)]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
 
-    /// Base mint for DLMM pair (for invariant checks only)
-    pub base_mint: Account<'info, Mint>,
+    /// Base mint for DLMM pair (for invariant checks only); may live under the other token program
+    #[account(mint::token_program = base_token_program)]
+    pub base_mint: InterfaceAccount<'info, Mint>,
 
     /// Program-owned quote treasury ATA
     #[account(
@@ -42,8 +44,9 @@ pub struct InitializeVault<'info> {
         payer = authority,
         associated_token::mint = quote_mint,
         associated_token::authority = fee_position_owner_pda,
+        associated_token::token_program = token_program,
     )]
-    pub treasury_quote: Account<'info, TokenAccount>,
+    pub treasury_quote: InterfaceAccount<'info, TokenAccount>,
 
     /// Program-owned base treasury ATA (should remain zero; used to detect base fees)
     #[account(
@@ -51,8 +54,9 @@ pub struct InitializeVault<'info> {
         payer = authority,
         associated_token::mint = base_mint,
         associated_token::authority = fee_position_owner_pda,
+        associated_token::token_program = base_token_program,
     )]
-    pub treasury_base: Account<'info, TokenAccount>,
+    pub treasury_base: InterfaceAccount<'info, TokenAccount>,
 
     /// PDA that will own the honorary position and treasuries
     /// CHECK: derived and used as authority only
@@ -66,7 +70,10 @@ pub struct InitializeVault<'info> {
     pub authority: Signer<'info>,
     
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    /// Token program of the quote mint
+    pub token_program: Interface<'info, TokenInterface>,
+    /// Token program of the base mint
+    pub base_token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub rent: Sysvar<'info, Rent>,
 }
@@ -74,11 +81,18 @@ pub struct InitializeVault<'info> {
 pub fn initialize_vault(
     ctx: Context<InitializeVault>,
     vault_id: [u8; 32],
-    creator_wallet: Pubkey,
-    investor_fee_share_bps: u16,
-    min_payout_lamports: u64,
-    daily_cap_lamports: Option<u64>,
+    params: InitializeVaultParams,
 ) -> Result<()> {
+    let InitializeVaultParams {
+        creator_wallet,
+        investor_fee_share_bps,
+        min_payout_lamports,
+        daily_cap_lamports,
+        roll_over_empty_days,
+        accrual_mode,
+        distribution_interval_secs,
+        epoch,
+    } = params;
     let vault = &mut ctx.accounts.vault;
     let distribution_state = &mut ctx.accounts.distribution_state;
     
@@ -88,6 +102,17 @@ pub fn initialize_vault(
         FeeRouterError::InvalidFeeShareBps
     );
     
+    require!(
+        (MIN_DISTRIBUTION_INTERVAL_SECS..=MAX_DISTRIBUTION_INTERVAL_SECS)
+            .contains(&distribution_interval_secs),
+        FeeRouterError::InvalidDistributionInterval
+    );
+    
+    require!(
+        epoch.iter().all(EpochConfig::is_valid),
+        FeeRouterError::InvalidEpochConfig
+    );
+    
     require!(
         !vault.is_initialized,
         FeeRouterError::VaultAlreadyInitialized
@@ -95,11 +120,22 @@ pub fn initialize_vault(
     
     // Initialize vault
     vault.vault_id = vault_id;
+    vault.admin = ctx.accounts.authority.key();
+    vault.pending_admin = None;
+    vault.pending_config = None;
+    vault.guardian = None;
+    vault.paused = false;
     vault.creator_wallet = creator_wallet;
     vault.quote_mint = ctx.accounts.quote_mint.key();
+    vault.base_mint = ctx.accounts.base_mint.key();
     vault.investor_fee_share_bps = investor_fee_share_bps;
     vault.min_payout_lamports = min_payout_lamports;
     vault.daily_cap_lamports = daily_cap_lamports;
+    vault.ata_rent_reimbursement = 0; // opted into through `queue_vault_config`
+    vault.roll_over_empty_days = roll_over_empty_days;
+    vault.accrual_mode = accrual_mode;
+    vault.distribution_interval_secs = distribution_interval_secs;
+    vault.epoch = epoch;
     vault.treasury_quote = ctx.accounts.treasury_quote.key();
     vault.treasury_base = ctx.accounts.treasury_base.key();
     vault.is_initialized = true;
@@ -114,10 +150,15 @@ pub fn initialize_vault(
     
     emit!(VaultInitialized {
         vault_id,
+        admin: ctx.accounts.authority.key(),
         creator: creator_wallet,
         investor_fee_share_bps,
         min_payout_lamports,
         daily_cap_lamports,
+        roll_over_empty_days,
+        accrual_mode,
+        distribution_interval_secs,
+        epoch,
         timestamp: Clock::get()?.unix_timestamp,
     });
     
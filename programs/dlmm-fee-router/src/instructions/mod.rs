@@ -1,9 +1,39 @@
 pub mod initialize_vault;
 pub mod initialize_fee_position;
-pub mod distribute_fees;
+pub mod claim_day_fees;
+pub mod distribute_page;
 pub mod update_investor_data;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod snapshot_investor_page;
+pub mod queue_vault_config;
+pub mod execute_vault_config;
+pub mod cancel_vault_config;
+pub mod set_guardian;
+pub mod set_paused;
+pub mod close_investor_page;
+pub mod close_vault;
+pub mod claim_investor_fees;
+pub mod claim_position_fees;
+pub mod remove_fee_position;
+pub mod skip_investor_page;
 
 pub use initialize_vault::*;
 pub use initialize_fee_position::*;
-pub use distribute_fees::*;
+pub use claim_day_fees::*;
+pub use distribute_page::*;
 pub use update_investor_data::*;
+pub use propose_admin::*;
+pub use accept_admin::*;
+pub use snapshot_investor_page::*;
+pub use queue_vault_config::*;
+pub use execute_vault_config::*;
+pub use cancel_vault_config::*;
+pub use set_guardian::*;
+pub use set_paused::*;
+pub use close_investor_page::*;
+pub use close_vault::*;
+pub use claim_investor_fees::*;
+pub use claim_position_fees::*;
+pub use remove_fee_position::*;
+pub use skip_investor_page::*;
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::FeeRouterError,
+    events::AdminProposed,
+    state::Vault,
+};
+
+#[derive(Accounts)]
+#[instruction(vault_id: [u8; 32])]
+pub struct ProposeAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault_id.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_initialized,
+        constraint = vault.admin == admin.key() @ FeeRouterError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn propose_admin(
+    ctx: Context<ProposeAdmin>,
+    vault_id: [u8; 32],
+    new_admin: Pubkey,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    
+    // Takes effect only once the proposed admin signs `accept_admin`
+    vault.pending_admin = Some(new_admin);
+    
+    emit!(AdminProposed {
+        vault_id,
+        current_admin: vault.admin,
+        proposed_admin: new_admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::FeeRouterError,
+    events::VaultConfigQueued,
+    state::{Vault, VaultConfig, PendingVaultConfig},
+};
+
+#[derive(Accounts)]
+#[instruction(vault_id: [u8; 32])]
+pub struct QueueVaultConfig<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault_id.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_initialized,
+        constraint = vault.admin == admin.key() @ FeeRouterError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn queue_vault_config(
+    ctx: Context<QueueVaultConfig>,
+    vault_id: [u8; 32],
+    config: VaultConfig,
+    effective_ts: i64,
+) -> Result<()> {
+    let current_ts = Clock::get()?.unix_timestamp;
+    let vault = &mut ctx.accounts.vault;
+    
+    require!(
+        config.investor_fee_share_bps <= MAX_BPS,
+        FeeRouterError::InvalidFeeShareBps
+    );
+    
+    // Investors get at least the full delay to react before the change can apply
+    require!(
+        effective_ts >= current_ts.saturating_add(CONFIG_CHANGE_DELAY_SECS),
+        FeeRouterError::ConfigChangeTooSoon
+    );
+    
+    // One change at a time; a queued change has to be cancelled before it is replaced
+    require!(
+        vault.pending_config.is_none(),
+        FeeRouterError::ConfigChangePending
+    );
+    
+    vault.pending_config = Some(PendingVaultConfig { config, effective_ts });
+    
+    emit!(VaultConfigQueued {
+        vault_id,
+        admin: vault.admin,
+        current_config: vault.config(),
+        new_config: config,
+        effective_ts,
+        timestamp: current_ts,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::FeeRouterError,
+    events::FeePositionRemoved,
+    state::{Vault, DistributionState, FeePositionRecord},
+    dlmm_integration,
+};
+
+#[derive(Accounts)]
+#[instruction(vault_id: [u8; 32], position_index: u32)]
+pub struct RemoveFeePosition<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault_id.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_initialized,
+        constraint = vault.admin == admin.key() @ FeeRouterError::Unauthorized
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [DISTRIBUTION_STATE_SEED, vault_id.as_ref()],
+        bump = distribution_state.bump
+    )]
+    pub distribution_state: Box<Account<'info, DistributionState>>,
+
+    /// Record of the position being deregistered
+    #[account(
+        mut,
+        seeds = [FEE_POSITION_SEED, vault_id.as_ref(), &position_index.to_le_bytes()],
+        bump = fee_position_record.bump,
+        constraint = fee_position_record.vault == vault.key() @ FeeRouterError::InvalidPoolConfiguration
+    )]
+    pub fee_position_record: Box<Account<'info, FeePositionRecord>>,
+
+    /// Record at the last position index, moved into the freed slot.
+    /// Required unless the last position is the one being removed.
+    #[account(
+        mut,
+        seeds = [FEE_POSITION_SEED, vault_id.as_ref(), &vault.position_count.saturating_sub(1).to_le_bytes()],
+        bump = last_position_record.bump,
+        constraint = last_position_record.vault == vault.key() @ FeeRouterError::InvalidPoolConfiguration
+    )]
+    pub last_position_record: Option<Box<Account<'info, FeePositionRecord>>>,
+
+    /// The DLMM position being closed
+    /// CHECK: Validated against the position record
+    #[account(
+        mut,
+        constraint = fee_position.key() == fee_position_record.position
+    )]
+    pub fee_position: AccountInfo<'info>,
+
+    /// The position owner PDA
+    /// CHECK: PDA derivation
+    #[account(
+        seeds = [VAULT_SEED, vault_id.as_ref(), INVESTOR_FEE_POSITION_OWNER_SEED],
+        bump
+    )]
+    pub fee_position_owner: AccountInfo<'info>,
+
+    /// The DLMM pool the position belongs to
+    /// CHECK: Validated against the position record
+    #[account(
+        mut,
+        constraint = pool.key() == fee_position_record.pool
+    )]
+    pub pool: AccountInfo<'info>,
+
+    /// Bin array covering the position's lower bin
+    /// CHECK: Validated by DLMM program
+    #[account(mut)]
+    pub bin_array_lower: AccountInfo<'info>,
+
+    /// Bin array covering the position's upper bin
+    /// CHECK: Validated by DLMM program
+    #[account(mut)]
+    pub bin_array_upper: AccountInfo<'info>,
+
+    /// DLMM event authority PDA
+    /// CHECK: PDA derivation under the DLMM program
+    #[account(
+        seeds = [dlmm_integration::EVENT_AUTHORITY_SEED],
+        bump,
+        seeds::program = dlmm_program.key()
+    )]
+    pub event_authority: AccountInfo<'info>,
+
+    /// DLMM program closing the position
+    /// CHECK: Program ID validation
+    #[account(
+        constraint = dlmm_program.key() == DLMM_PROGRAM_ID
+    )]
+    pub dlmm_program: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+pub fn remove_fee_position(
+    ctx: Context<RemoveFeePosition>,
+    vault_id: [u8; 32],
+    position_index: u32,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let last_index = vault.position_count
+        .checked_sub(1)
+        .ok_or(FeeRouterError::InvalidPoolConfiguration)?;
+    require!(position_index <= last_index, FeeRouterError::InvalidPoolConfiguration);
+
+    // Only a position claimed for the upcoming day can be removed, so none of its fees are
+    // lost: they stay in the pending pool, and the position no longer counts towards the day
+    let distribution_state = &mut ctx.accounts.distribution_state;
+    let removed = &ctx.accounts.fee_position_record;
+    require!(
+        removed.claimed_for_day == distribution_state.claim_round(),
+        FeeRouterError::PositionNotClaimed
+    );
+    distribution_state.positions_claimed -= 1;
+    let (position, pool) = (removed.position, removed.pool);
+
+    // Close the DLMM position through its owner PDA; the rent goes to the admin
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        VAULT_SEED,
+        vault_id.as_ref(),
+        INVESTOR_FEE_POSITION_OWNER_SEED,
+        &[ctx.bumps.fee_position_owner],
+    ]];
+    dlmm_integration::cpi::close_position(
+        dlmm_integration::cpi::ClosePosition {
+            position: ctx.accounts.fee_position.to_account_info(),
+            lb_pair: ctx.accounts.pool.to_account_info(),
+            bin_array_lower: ctx.accounts.bin_array_lower.to_account_info(),
+            bin_array_upper: ctx.accounts.bin_array_upper.to_account_info(),
+            sender: ctx.accounts.fee_position_owner.to_account_info(),
+            rent_receiver: ctx.accounts.admin.to_account_info(),
+            event_authority: ctx.accounts.event_authority.to_account_info(),
+            program: ctx.accounts.dlmm_program.to_account_info(),
+        },
+        signer_seeds,
+    )?;
+
+    // Position indexes stay contiguous: the last record moves into the freed slot
+    let admin = ctx.accounts.admin.to_account_info();
+    if position_index == last_index {
+        ctx.accounts.fee_position_record.close(admin)?;
+    } else {
+        let last = ctx.accounts.last_position_record
+            .as_ref()
+            .ok_or(FeeRouterError::InvalidPoolConfiguration)?;
+        let record = &mut ctx.accounts.fee_position_record;
+        record.position = last.position;
+        record.pool = last.pool;
+        record.lower_bin_id = last.lower_bin_id;
+        record.upper_bin_id = last.upper_bin_id;
+        record.claimed_for_day = last.claimed_for_day;
+        record.total_claimed = last.total_claimed;
+        last.close(admin)?;
+    }
+
+    vault.position_count = last_index;
+    vault.position_initialized = vault.position_count > 0;
+
+    emit!(FeePositionRemoved {
+        vault_id,
+        position_index,
+        position,
+        pool,
+        position_count: vault.position_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
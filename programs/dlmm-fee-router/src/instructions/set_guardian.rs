@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::FeeRouterError,
+    events::GuardianUpdated,
+    state::Vault,
+};
+
+#[derive(Accounts)]
+#[instruction(vault_id: [u8; 32])]
+pub struct SetGuardian<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault_id.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_initialized,
+        constraint = vault.admin == admin.key() @ FeeRouterError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn set_guardian(
+    ctx: Context<SetGuardian>,
+    vault_id: [u8; 32],
+    guardian: Option<Pubkey>,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let previous_guardian = vault.guardian;
+    vault.guardian = guardian;
+    
+    emit!(GuardianUpdated {
+        vault_id,
+        previous_guardian,
+        new_guardian: guardian,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::FeeRouterError,
+    events::PauseStateChanged,
+    state::Vault,
+};
+
+#[derive(Accounts)]
+#[instruction(vault_id: [u8; 32])]
+pub struct SetPaused<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault_id.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_initialized,
+        constraint = vault.can_pause(&authority.key()) @ FeeRouterError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn set_paused(
+    ctx: Context<SetPaused>,
+    vault_id: [u8; 32],
+    paused: bool,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.paused = paused;
+    
+    emit!(PauseStateChanged {
+        vault_id,
+        authority: ctx.accounts.authority.key(),
+        paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
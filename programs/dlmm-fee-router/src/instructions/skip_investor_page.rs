@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    constants::*,
+    errors::FeeRouterError,
+    events::InvestorPageSkipped,
+    state::{Vault, DistributionState, InvestorPage, PageBitmap},
+    instructions::distribute_page::{close_distribution_day, mark_page_processed, CreatorPayoutAccounts},
+};
+
+#[derive(Accounts)]
+#[instruction(vault_id: [u8; 32], page: u32)]
+pub struct SkipInvestorPage<'info> {
+    #[account(
+        seeds = [VAULT_SEED, vault_id.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_initialized,
+        constraint = vault.admin == admin.key() @ FeeRouterError::Unauthorized
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [DISTRIBUTION_STATE_SEED, vault_id.as_ref()],
+        bump = distribution_state.bump
+    )]
+    pub distribution_state: Box<Account<'info, DistributionState>>,
+
+    /// Investor page being skipped
+    #[account(
+        seeds = [INVESTOR_PAGE_SEED, vault_id.as_ref(), &page.to_le_bytes()],
+        bump = investor_page.bump,
+        constraint = investor_page.vault == vault.key() @ FeeRouterError::InvalidInvestorData
+    )]
+    pub investor_page: Box<Account<'info, InvestorPage>>,
+
+    /// Processed-page bitmap chunk covering `page` (created on first use)
+    /// CHECK: PDA derivation; created and deserialized in instruction
+    #[account(
+        mut,
+        seeds = [
+            PAGE_BITMAP_SEED,
+            vault_id.as_ref(),
+            &PageBitmap::chunk_for(page).to_le_bytes()
+        ],
+        bump
+    )]
+    pub page_bitmap: AccountInfo<'info>,
+
+    /// Program-owned quote treasury ATA (pays the creator if the skip closes the day)
+    #[account(
+        mut,
+        constraint = treasury_quote.key() == vault.treasury_quote,
+        constraint = treasury_quote.mint == vault.quote_mint @ FeeRouterError::InvalidQuoteMint
+    )]
+    pub treasury_quote: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Creator's quote token account
+    #[account(
+        mut,
+        constraint = creator_quote_account.owner == vault.creator_wallet,
+        constraint = creator_quote_account.mint == vault.quote_mint @ FeeRouterError::InvalidQuoteMint
+    )]
+    pub creator_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The treasury owner PDA
+    /// CHECK: PDA derivation
+    #[account(
+        seeds = [VAULT_SEED, vault_id.as_ref(), INVESTOR_FEE_POSITION_OWNER_SEED],
+        bump
+    )]
+    pub fee_position_owner: AccountInfo<'info>,
+
+    #[account(
+        constraint = quote_mint.key() == vault.quote_mint @ FeeRouterError::InvalidQuoteMint
+    )]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn skip_investor_page(
+    ctx: Context<SkipInvestorPage>,
+    vault_id: [u8; 32],
+    page: u32,
+) -> Result<()> {
+    let current_ts = Clock::get()?.unix_timestamp;
+
+    // Only the page the open day is stuck on can be skipped
+    require!(
+        ctx.accounts.distribution_state.is_day_open(),
+        FeeRouterError::DayNotStarted
+    );
+    let current_day = ctx.accounts.distribution_state.current_day;
+    require!(
+        page == ctx.accounts.distribution_state.current_page
+            && page < ctx.accounts.distribution_state.day_page_count,
+        FeeRouterError::InvalidPageNumber
+    );
+    require!(
+        ctx.accounts.investor_page.snapshot_ts == ctx.accounts.distribution_state.snapshot_ts,
+        FeeRouterError::SnapshotIncomplete
+    );
+
+    mark_page_processed(
+        &ctx.accounts.admin,
+        &ctx.accounts.page_bitmap,
+        &ctx.accounts.system_program,
+        &mut ctx.accounts.distribution_state,
+        ctx.bumps.page_bitmap,
+        vault_id,
+        page,
+    )?;
+
+    // The page's investors are paid nothing today; their whole share stays with investors
+    let distribution_state = &mut ctx.accounts.distribution_state;
+    let carried_over = distribution_state.allocate_page_share(ctx.accounts.investor_page.total_locked)?;
+    distribution_state.carry_forward(carried_over)?;
+
+    emit!(InvestorPageSkipped {
+        vault_id,
+        page,
+        carried_over,
+        distribution_day: current_day,
+        timestamp: current_ts,
+    });
+
+    distribution_state.pages_processed += 1;
+    if distribution_state.all_pages_done() {
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            VAULT_SEED,
+            vault_id.as_ref(),
+            INVESTOR_FEE_POSITION_OWNER_SEED,
+            &[ctx.bumps.fee_position_owner],
+        ]];
+        close_distribution_day(
+            distribution_state,
+            CreatorPayoutAccounts {
+                token_program: ctx.accounts.token_program.to_account_info(),
+                treasury_quote: ctx.accounts.treasury_quote.to_account_info(),
+                quote_mint: &ctx.accounts.quote_mint,
+                creator_quote_account: ctx.accounts.creator_quote_account.to_account_info(),
+                fee_position_owner: ctx.accounts.fee_position_owner.to_account_info(),
+            },
+            signer_seeds,
+            vault_id,
+            current_ts,
+        )?;
+    } else {
+        distribution_state.current_page += 1;
+        distribution_state.page_cursor = distribution_state.page_cursor.saturating_add(1);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::FeeRouterError,
+    events::InvestorPageSnapshotted,
+    state::{Vault, DistributionState, InvestorPage, InvestorRecord},
+    streamflow_integration,
+    utils::load_account,
+};
+
+#[derive(Accounts)]
+#[instruction(vault_id: [u8; 32], page: u32)]
+pub struct SnapshotInvestorPage<'info> {
+    #[account(
+        seeds = [VAULT_SEED, vault_id.as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_initialized,
+        constraint = !vault.paused @ FeeRouterError::VaultPaused
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [DISTRIBUTION_STATE_SEED, vault_id.as_ref()],
+        bump = distribution_state.bump
+    )]
+    pub distribution_state: Box<Account<'info, DistributionState>>,
+
+    /// Investor page being snapshotted
+    #[account(
+        mut,
+        seeds = [INVESTOR_PAGE_SEED, vault_id.as_ref(), &page.to_le_bytes()],
+        bump = investor_page.bump,
+        constraint = investor_page.vault == vault.key() @ FeeRouterError::InvalidInvestorData
+    )]
+    pub investor_page: Box<Account<'info, InvestorPage>>,
+
+    pub crank_operator: Signer<'info>,
+
+    // Remaining accounts are, for every investor registered on the page, in page order:
+    // Format: [investor_record_0, stream_0, investor_record_1, stream_1, ...]
+}
+
+/// Remaining accounts supplied per registered investor: record, stream
+const ACCOUNTS_PER_INVESTOR: usize = 2;
+
+/// Accounts of a `snapshot_investor_page` transaction besides the per-investor ones:
+/// the `SnapshotInvestorPage` accounts, this program and a compute budget instruction
+const SNAPSHOT_PAGE_FIXED_ACCOUNTS: usize = 6;
+
+// A full page must be snapshotted in a single transaction
+const _: () = assert!(
+    SNAPSHOT_PAGE_FIXED_ACCOUNTS + MAX_INVESTORS_PER_PAGE * ACCOUNTS_PER_INVESTOR <= MAX_TX_ACCOUNT_LOCKS
+);
+
+pub fn snapshot_investor_page<'info>(
+    ctx: Context<'_, '_, '_, 'info, SnapshotInvestorPage<'info>>,
+    vault_id: [u8; 32],
+    page: u32,
+) -> Result<()> {
+    let current_ts = Clock::get()?.unix_timestamp;
+    let vault = &ctx.accounts.vault;
+    let distribution_state = &mut ctx.accounts.distribution_state;
+    let investor_page = &mut ctx.accounts.investor_page;
+
+    // Snapshots are taken for the upcoming day, never while one is being paid out
+    require!(!distribution_state.is_day_open(), FeeRouterError::DayInProgress);
+    require!(
+        distribution_state.can_distribute(current_ts, vault),
+        FeeRouterError::DistributionWindowNotReached
+    );
+
+    // Page 0 starts the snapshot; later pages follow in order. Once a snapshot is under way for
+    // the upcoming day (closing a day clears it), only the admin may restart it, so nobody else
+    // can wipe a crank's progress or a completed snapshot
+    if page == 0 {
+        require!(
+            distribution_state.pages_snapshotted == 0
+                || ctx.accounts.crank_operator.key() == vault.admin,
+            FeeRouterError::SnapshotInProgress
+        );
+        distribution_state.begin_snapshot(current_ts);
+    }
+    require!(
+        page == distribution_state.pages_snapshotted && page < vault.investor_page_count,
+        FeeRouterError::InvalidPageNumber
+    );
+
+    require!(
+        ctx.remaining_accounts.len() == investor_page.investors.len() * ACCOUNTS_PER_INVESTOR,
+        FeeRouterError::InvalidInvestorData
+    );
+
+    let snapshot_ts = distribution_state.snapshot_ts;
+    let mut page_locked = 0u64;
+    for (registered, accounts) in investor_page.investors.iter()
+        .zip(ctx.remaining_accounts.chunks_exact(ACCOUNTS_PER_INVESTOR))
+    {
+        let (_, locked_amount) = read_investor_lock(
+            vault,
+            investor_page,
+            registered,
+            &accounts[0],
+            &accounts[1],
+            snapshot_ts,
+        )?;
+        page_locked = page_locked
+            .checked_add(locked_amount)
+            .ok_or(FeeRouterError::MathOverflow)?;
+    }
+
+    investor_page.total_locked = page_locked;
+    investor_page.snapshot_ts = snapshot_ts;
+
+    distribution_state.day_total_locked = distribution_state.day_total_locked
+        .checked_add(page_locked)
+        .ok_or(FeeRouterError::MathOverflow)?;
+    distribution_state.pages_snapshotted += 1;
+
+    emit!(InvestorPageSnapshotted {
+        vault_id,
+        page,
+        page_locked,
+        day_total_locked: distribution_state.day_total_locked,
+        pages_snapshotted: distribution_state.pages_snapshotted,
+        snapshot_ts,
+        timestamp: current_ts,
+    });
+
+    Ok(())
+}
+
+/// Load a page's investor record and read its still-locked amount at `snapshot_ts`
+pub(crate) fn read_investor_lock(
+    vault: &Vault,
+    investor_page: &InvestorPage,
+    registered: &Pubkey,
+    record_acc: &AccountInfo,
+    stream_acc: &AccountInfo,
+    snapshot_ts: i64,
+) -> Result<(InvestorRecord, u64)> {
+    require_keys_eq!(record_acc.key(), *registered, FeeRouterError::InvalidInvestorData);
+    let record = load_account::<InvestorRecord>(record_acc)?;
+    require_keys_eq!(record.vault, investor_page.vault, FeeRouterError::InvalidInvestorData);
+    require_keys_eq!(stream_acc.key(), record.stream_pubkey, FeeRouterError::InvalidStreamAccount);
+
+    let locked_amount = streamflow_integration::read_locked_amount(
+        stream_acc,
+        &record.investor,
+        &vault.base_mint,
+        snapshot_ts,
+    )?;
+    Ok((record, locked_amount))
+}
@@ -1,49 +1,218 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use crate::{
     constants::*,
+    errors::FeeRouterError,
+    events::InvestorDataUpdated,
     state::{Vault, InvestorRecord, InvestorPage},
+    utils::{create_pda_account, load_account, store_account},
 };
 
+/// Registration entry for a single investor
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct InvestorAllocation {
+    /// Investor wallet address
+    pub investor: Pubkey,
+
+    /// Streamflow stream pubkey for this investor
+    pub stream_pubkey: Pubkey,
+
+    /// Initial allocation amount
+    pub initial_allocation: u64,
+}
+
 #[derive(Accounts)]
-#[instruction(vault_id: [u8; 32], total_allocation: u64)]
+#[instruction(vault_id: [u8; 32], page: u32)]
 pub struct UpdateInvestorData<'info> {
     #[account(
         mut,
         seeds = [VAULT_SEED, vault_id.as_ref()],
         bump = vault.bump,
-        constraint = vault.is_initialized
+        constraint = vault.is_initialized,
+        constraint = vault.admin == authority.key() @ FeeRouterError::Unauthorized,
+        constraint = !vault.paused @ FeeRouterError::VaultPaused
     )]
     pub vault: Account<'info, Vault>,
-    
+
+    /// Investor page receiving the registrations (created on first use)
+    /// CHECK: PDA derivation; created and deserialized in instruction
+    #[account(
+        mut,
+        seeds = [INVESTOR_PAGE_SEED, vault_id.as_ref(), &page.to_le_bytes()],
+        bump
+    )]
+    pub investor_page: AccountInfo<'info>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
-    
+
     pub system_program: Program<'info, System>,
-    
-    // Remaining accounts are investor records to update
+
+    // Remaining accounts are InvestorRecord PDAs, one per entry in `investors`
+    // Format: [investor_record_0, investor_record_1, ...]
 }
 
-pub fn update_investor_data(
-    ctx: Context<UpdateInvestorData>,
-    _vault_id: [u8; 32],
-    total_allocation: u64,
+pub fn update_investor_data<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateInvestorData<'info>>,
+    vault_id: [u8; 32],
+    page: u32,
+    investors: Vec<InvestorAllocation>,
 ) -> Result<()> {
+    require!(
+        !investors.is_empty() && investors.len() == ctx.remaining_accounts.len(),
+        FeeRouterError::InvalidInvestorData
+    );
+
+    let current_ts = Clock::get()?.unix_timestamp;
+    let vault_key = ctx.accounts.vault.key();
+    let page_bytes = page.to_le_bytes();
+
+    let mut investor_page = if ctx.accounts.investor_page.data_is_empty() {
+        // Pages are opened in order; a new page is only valid once the previous one is full
+        require!(
+            page as usize == ctx.accounts.vault.investor_count as usize / MAX_INVESTORS_PER_PAGE,
+            FeeRouterError::InvalidPageNumber
+        );
+        create_pda_account(
+            &ctx.accounts.authority,
+            &ctx.accounts.investor_page,
+            &ctx.accounts.system_program,
+            InvestorPage::len(0),
+            &[
+                INVESTOR_PAGE_SEED,
+                vault_id.as_ref(),
+                &page_bytes,
+                &[ctx.bumps.investor_page],
+            ],
+        )?;
+        ctx.accounts.vault.investor_page_count = page + 1;
+        InvestorPage {
+            vault: vault_key,
+            page,
+            investor_count: 0,
+            investors: Vec::new(),
+            total_locked: 0,
+            snapshot_ts: 0,
+            last_update_ts: current_ts,
+            bump: ctx.bumps.investor_page,
+        }
+    } else {
+        load_account::<InvestorPage>(&ctx.accounts.investor_page)?
+    };
+    require_keys_eq!(investor_page.vault, vault_key, FeeRouterError::InvalidInvestorData);
+
     let vault = &mut ctx.accounts.vault;
-    
-    // Update total allocation (Y0)
-    vault.total_investor_allocation = total_allocation;
-    
-    // Process investor records from remaining accounts
-    // This would typically:
-    // 1. Create or update InvestorRecord accounts
-    // 2. Organize investors into pages
-    // 3. Store stream pubkeys and initial allocations
-    
-    // Note: In a full implementation, this would handle:
-    // - Creating InvestorRecord PDAs for each investor
-    // - Organizing investors into pages for efficient pagination
-    // - Storing Streamflow stream pubkeys for each investor
-    // - Validating investor data
-    
+
+    for (entry, record_info) in investors.iter().zip(ctx.remaining_accounts.iter()) {
+        require!(
+            entry.initial_allocation > 0,
+            FeeRouterError::InvalidInvestorData
+        );
+
+        let (expected_record, record_bump) = Pubkey::find_program_address(
+            &[INVESTOR_RECORD_SEED, vault_id.as_ref(), entry.investor.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(record_info.key(), expected_record, FeeRouterError::InvalidInvestorData);
+
+        let record = if record_info.data_is_empty() {
+            // New investor: append to the page this call is filling
+            require!(
+                page as usize == vault.investor_count as usize / MAX_INVESTORS_PER_PAGE,
+                FeeRouterError::InvalidPageNumber
+            );
+            let page_index = vault.investor_count % MAX_INVESTORS_PER_PAGE as u32;
+
+            create_pda_account(
+                &ctx.accounts.authority,
+                record_info,
+                &ctx.accounts.system_program,
+                InvestorRecord::LEN,
+                &[
+                    INVESTOR_RECORD_SEED,
+                    vault_id.as_ref(),
+                    entry.investor.as_ref(),
+                    &[record_bump],
+                ],
+            )?;
+
+            investor_page.investors.push(record_info.key());
+            investor_page.investor_count += 1;
+            vault.investor_count = vault.investor_count
+                .checked_add(1)
+                .ok_or(FeeRouterError::MathOverflow)?;
+            vault.total_investor_allocation = vault.total_investor_allocation
+                .checked_add(entry.initial_allocation)
+                .ok_or(FeeRouterError::MathOverflow)?;
+
+            InvestorRecord {
+                vault: vault_key,
+                investor: entry.investor,
+                stream_pubkey: entry.stream_pubkey,
+                initial_allocation: entry.initial_allocation,
+                total_fees_received: 0,
+                last_distribution_ts: 0,
+                page,
+                page_index,
+                bump: record_bump,
+                fees_owed: 0,
+                _reserved: [0u8; 24],
+            }
+        } else {
+            // Existing investor: records can only be updated through their own page
+            let mut record = load_account::<InvestorRecord>(record_info)?;
+            require_keys_eq!(record.vault, vault_key, FeeRouterError::InvalidInvestorData);
+            require!(record.page == page, FeeRouterError::InvalidPageNumber);
+
+            // Y0 is the sum of record allocations; swap the old allocation for the new one
+            vault.total_investor_allocation = vault.total_investor_allocation
+                .checked_sub(record.initial_allocation)
+                .ok_or(FeeRouterError::MathOverflow)?
+                .checked_add(entry.initial_allocation)
+                .ok_or(FeeRouterError::MathOverflow)?;
+
+            record.stream_pubkey = entry.stream_pubkey;
+            record.initial_allocation = entry.initial_allocation;
+            record
+        };
+
+        store_account(&record, record_info)?;
+    }
+
+    investor_page.last_update_ts = current_ts;
+
+    // Grow the page to fit newly appended investors
+    let page_info = &ctx.accounts.investor_page;
+    let new_len = InvestorPage::len(investor_page.investors.len());
+    if new_len > page_info.data_len() {
+        let rent_shortfall = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(page_info.lamports());
+        if rent_shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: page_info.clone(),
+                    },
+                ),
+                rent_shortfall,
+            )?;
+        }
+        page_info.realloc(new_len, false)?;
+    }
+    store_account(&investor_page, page_info)?;
+
+    emit!(InvestorDataUpdated {
+        vault_id,
+        page,
+        page_investor_count: investor_page.investor_count,
+        investor_count: vault.investor_count,
+        investor_page_count: vault.investor_page_count,
+        total_investor_allocation: vault.total_investor_allocation,
+        timestamp: current_ts,
+    });
+
     Ok(())
 }
@@ -6,8 +6,12 @@ pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod dlmm_integration;
+pub mod streamflow_integration;
+pub mod token_integration;
+pub mod utils;
 
 use instructions::*;
+use state::{InitializeVaultParams, VaultConfig};
 
 declare_id!("FeeRouter11111111111111111111111111111111111");
 
@@ -19,22 +23,12 @@ pub mod dlmm_fee_router {
     pub fn initialize_vault(
         ctx: Context<InitializeVault>,
         vault_id: [u8; 32],
-        creator_wallet: Pubkey,
-        investor_fee_share_bps: u16,
-        min_payout_lamports: u64,
-        daily_cap_lamports: Option<u64>,
+        params: InitializeVaultParams,
     ) -> Result<()> {
-        instructions::initialize_vault(
-            ctx,
-            vault_id,
-            creator_wallet,
-            investor_fee_share_bps,
-            min_payout_lamports,
-            daily_cap_lamports,
-        )
+        instructions::initialize_vault(ctx, vault_id, params)
     }
 
-    /// Initialize the honorary fee position for quote-only fees
+    /// Create and register another honorary fee position for quote-only fees
     pub fn initialize_fee_position(
         ctx: Context<InitializeFeePosition>,
         vault_id: [u8; 32],
@@ -42,22 +36,154 @@ pub mod dlmm_fee_router {
         instructions::initialize_fee_position(ctx, vault_id)
     }
 
-    /// Claim fees and distribute to investors (paginated, once per 24h)
-    pub fn distribute_fees(
-        ctx: Context<DistributeFees>,
+    /// Deregister a fee position; the last position takes over its index (admin only)
+    pub fn remove_fee_position(
+        ctx: Context<RemoveFeePosition>,
+        vault_id: [u8; 32],
+        position_index: u32,
+    ) -> Result<()> {
+        instructions::remove_fee_position(ctx, vault_id, position_index)
+    }
+
+    /// Snapshot the locked amounts of an investor page ahead of the next distribution day
+    pub fn snapshot_investor_page<'info>(
+        ctx: Context<'_, '_, '_, 'info, SnapshotInvestorPage<'info>>,
+        vault_id: [u8; 32],
+        page: u32,
+    ) -> Result<()> {
+        instructions::snapshot_investor_page(ctx, vault_id, page)
+    }
+
+    /// Claim one registered position's quote fees ahead of the next distribution day
+    pub fn claim_position_fees(
+        ctx: Context<ClaimPositionFees>,
+        vault_id: [u8; 32],
+        position_index: u32,
+    ) -> Result<()> {
+        instructions::claim_position_fees(ctx, vault_id, position_index)
+    }
+
+    /// Open the distribution day from the claimed position fees and freeze the investor pool
+    pub fn claim_day_fees(
+        ctx: Context<ClaimDayFees>,
+        vault_id: [u8; 32],
+    ) -> Result<()> {
+        instructions::claim_day_fees(ctx, vault_id)
+    }
+
+    /// Pay one investor page of the open day; the last page pays the creator and closes the day
+    pub fn distribute_page<'info>(
+        ctx: Context<'_, '_, '_, 'info, DistributePage<'info>>,
         vault_id: [u8; 32],
         page: u32,
         is_final_page: bool,
+        create_missing_atas: bool,
+    ) -> Result<()> {
+        instructions::distribute_page(ctx, vault_id, page, is_final_page, create_missing_atas)
+    }
+
+    /// Skip the open day's current page, carrying its investor share over (admin only)
+    pub fn skip_investor_page(
+        ctx: Context<SkipInvestorPage>,
+        vault_id: [u8; 32],
+        page: u32,
+    ) -> Result<()> {
+        instructions::skip_investor_page(ctx, vault_id, page)
+    }
+
+    /// Register or update investors on a page; Y0 is recomputed from the records
+    pub fn update_investor_data<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateInvestorData<'info>>,
+        vault_id: [u8; 32],
+        page: u32,
+        investors: Vec<InvestorAllocation>,
+    ) -> Result<()> {
+        instructions::update_investor_data(ctx, vault_id, page, investors)
+    }
+
+    /// Propose a new vault admin (step 1 of the two-step transfer)
+    pub fn propose_admin(
+        ctx: Context<ProposeAdmin>,
+        vault_id: [u8; 32],
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_admin(ctx, vault_id, new_admin)
+    }
+
+    /// Accept a pending admin proposal (step 2, signed by the proposed admin)
+    pub fn accept_admin(
+        ctx: Context<AcceptAdmin>,
+        vault_id: [u8; 32],
+    ) -> Result<()> {
+        instructions::accept_admin(ctx, vault_id)
+    }
+
+    /// Queue a change of the vault's distribution parameters behind the timelock
+    pub fn queue_vault_config(
+        ctx: Context<QueueVaultConfig>,
+        vault_id: [u8; 32],
+        config: VaultConfig,
+        effective_ts: i64,
+    ) -> Result<()> {
+        instructions::queue_vault_config(ctx, vault_id, config, effective_ts)
+    }
+
+    /// Apply the queued config change once its timelock has elapsed (permissionless)
+    pub fn execute_vault_config(
+        ctx: Context<ExecuteVaultConfig>,
+        vault_id: [u8; 32],
+    ) -> Result<()> {
+        instructions::execute_vault_config(ctx, vault_id)
+    }
+
+    /// Drop the queued config change (admin only)
+    pub fn cancel_vault_config(
+        ctx: Context<CancelVaultConfig>,
+        vault_id: [u8; 32],
+    ) -> Result<()> {
+        instructions::cancel_vault_config(ctx, vault_id)
+    }
+
+    /// Set or clear the guardian key that may pause the vault (admin only)
+    pub fn set_guardian(
+        ctx: Context<SetGuardian>,
+        vault_id: [u8; 32],
+        guardian: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_guardian(ctx, vault_id, guardian)
+    }
+
+    /// Pause or unpause the vault (admin or guardian)
+    pub fn set_paused(
+        ctx: Context<SetPaused>,
+        vault_id: [u8; 32],
+        paused: bool,
+    ) -> Result<()> {
+        instructions::set_paused(ctx, vault_id, paused)
+    }
+
+    /// Close the vault's last investor page and its records (admin only, vault paused)
+    pub fn close_investor_page<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseInvestorPage<'info>>,
+        vault_id: [u8; 32],
+        page: u32,
+    ) -> Result<()> {
+        instructions::close_investor_page(ctx, vault_id, page)
+    }
+
+    /// Sweep the treasury and close the vault once all investor accounts are closed
+    pub fn close_vault<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseVault<'info>>,
+        vault_id: [u8; 32],
     ) -> Result<()> {
-        instructions::distribute_fees(ctx, vault_id, page, is_final_page)
+        instructions::close_vault(ctx, vault_id)
     }
 
-    /// Update investor allocation data (called when needed)
-    pub fn update_investor_data(
-        ctx: Context<UpdateInvestorData>,
+    /// Withdraw fees accrued to the signing investor's record (accrual mode)
+    pub fn claim_investor_fees(
+        ctx: Context<ClaimInvestorFees>,
         vault_id: [u8; 32],
-        total_allocation: u64,
     ) -> Result<()> {
-        instructions::update_investor_data(ctx, vault_id, total_allocation)
+        instructions::claim_investor_fees(ctx, vault_id)
     }
 }
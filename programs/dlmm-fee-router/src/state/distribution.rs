@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
 
+use crate::{errors::FeeRouterError, state::Vault, utils::mul_div};
+
+/// Accounting invariant: `treasury_quote` always holds the undistributed `carry_over`,
+/// the unclaimed `fees_owed` and the `pending_claimed_fees` of the next day, plus whatever of the in-flight day's funds
+/// (`day_claimed_fees + day_carry_in`) has not yet been paid out or moved back into `carry_over`.
 #[account]
-#[derive(Default)]
 pub struct DistributionState {
     /// Associated vault
     pub vault: Pubkey,
@@ -9,15 +13,21 @@ pub struct DistributionState {
     /// Last distribution timestamp
     pub last_distribution_ts: i64,
     
-    /// Current distribution day number
+    /// Current distribution day number (epoch index when the vault uses aligned epochs)
     pub current_day: u64,
     
     /// Amount distributed so far today
     pub daily_distributed: u64,
     
-    /// Carry-over amount from previous day
+    /// Undistributed investor funds (dust, rounding, cap overflow) awaiting a later day
     pub carry_over: u64,
     
+    /// Investor fees credited in accrual mode and not yet claimed
+    pub fees_owed: u64,
+    
+    /// Carry-over taken into the current day's investor pool
+    pub day_carry_in: u64,
+    
     /// Current page being processed
     pub current_page: u32,
     
@@ -29,6 +39,27 @@ pub struct DistributionState {
     
     /// Total distributed to investors this day
     pub day_investor_total: u64,
+    
+    /// Amount of this day's funds moved into `carry_over`
+    pub day_carried: u64,
+    
+    /// Still-locked total across all investor pages at `snapshot_ts`
+    pub day_total_locked: u64,
+    
+    /// Timestamp the investor locked amounts are evaluated at for the day
+    pub snapshot_ts: i64,
+    
+    /// Number of investor pages snapshotted (in order) since `snapshot_ts`
+    pub pages_snapshotted: u32,
+    
+    /// Investor pool frozen when the day opens (investor share of claimed fees + carry-in)
+    pub day_investor_pool: u64,
+    
+    /// Part of the investor pool already assigned to processed pages
+    pub day_pool_allocated: u64,
+    
+    /// Part of the creator share paid to cranks for investor ATAs they created
+    pub day_ata_reimbursed: u64,
 
     /// Pagination cursor to ensure idempotency across retries
     pub page_cursor: u64,
@@ -36,14 +67,23 @@ pub struct DistributionState {
     /// Count of processed pages
     pub pages_processed: u32,
 
-    /// Bitmap of processed pages (supports up to 128 pages per day)
-    pub pages_done_mask: u128,
+    /// Investor pages to process for the current day (fixed when the day opens)
+    pub day_page_count: u32,
+    
+    /// Quote fees claimed from positions for the upcoming day
+    pub pending_claimed_fees: u64,
+    
+    /// Number of positions claimed for the upcoming day
+    pub positions_claimed: u32,
+    
+    /// Number of PageBitmap chunks created (chunks `0..bitmap_chunks` exist)
+    pub bitmap_chunks: u32,
     
     /// Bump seed for PDA derivation
     pub bump: u8,
     
     /// Reserved space for future upgrades
-    pub _reserved: [u8; 64],
+    pub _reserved: [u8; 40],
 }
 
 impl DistributionState {
@@ -53,42 +93,270 @@ impl DistributionState {
         8 + // current_day
         8 + // daily_distributed
         8 + // carry_over
+        8 + // fees_owed
+        8 + // day_carry_in
         4 + // current_page
         1 + // day_complete
         8 + // day_claimed_fees
         8 + // day_investor_total
+        8 + // day_carried
+        8 + // day_total_locked
+        8 + // snapshot_ts
+        4 + // pages_snapshotted
+        8 + // day_investor_pool
+        8 + // day_pool_allocated
+        8 + // day_ata_reimbursed
         8 + // page_cursor
         4 + // pages_processed
-        16 + // pages_done_mask
+        4 + // day_page_count
+        8 + // pending_claimed_fees
+        4 + // positions_claimed
+        4 + // bitmap_chunks
         1 + // bump
-        64; // _reserved
+        40; // _reserved
     
-    pub fn can_distribute(&self, current_ts: i64) -> bool {
-        current_ts >= self.last_distribution_ts + crate::constants::SECONDS_PER_DAY
+    pub fn can_distribute(&self, current_ts: i64, vault: &Vault) -> bool {
+        match vault.epoch {
+            // Aligned epochs: a new day opens once the clock enters a later epoch
+            Some(epoch) => epoch.epoch_index(current_ts) > self.current_day,
+            None => current_ts >= self.last_distribution_ts.saturating_add(vault.distribution_interval_secs),
+        }
     }
     
-    pub fn start_new_day(&mut self, current_ts: i64) {
+    pub fn start_new_day(&mut self, current_ts: i64, vault: &Vault) {
         self.last_distribution_ts = current_ts;
-        self.current_day += 1;
+        self.current_day = match vault.epoch {
+            Some(epoch) => epoch.epoch_index(current_ts),
+            None => self.current_day + 1,
+        };
         self.daily_distributed = 0;
         self.current_page = 0;
         self.day_complete = false;
         self.day_claimed_fees = 0;
+        self.day_carry_in = 0;
         self.day_investor_total = 0;
+        self.day_carried = 0;
+        self.day_investor_pool = 0;
+        self.day_pool_allocated = 0;
+        self.day_ata_reimbursed = 0;
         self.page_cursor = 0;
         self.pages_processed = 0;
-        self.pages_done_mask = 0;
+        self.day_page_count = 0;
+    }
+
+    /// A day has been opened and not yet closed
+    pub fn is_day_open(&self) -> bool {
+        self.current_day > 0 && !self.day_complete
+    }
+
+    /// Restart the locked-amount snapshot for the upcoming day
+    pub fn begin_snapshot(&mut self, current_ts: i64) {
+        self.snapshot_ts = current_ts;
+        self.pages_snapshotted = 0;
+        self.day_total_locked = 0;
+    }
+
+    /// Close the current day; the next day needs a fresh snapshot
+    pub fn close_day(&mut self) {
+        self.day_complete = true;
+        self.pages_snapshotted = 0;
+    }
+
+    /// Claim round positions are claimed for before the next day opens
+    pub fn claim_round(&self) -> u64 {
+        self.current_day + 1
+    }
+
+    /// Hand the fees claimed from every position over to the day being opened
+    pub fn take_pending_claims(&mut self) -> u64 {
+        let claimed = self.pending_claimed_fees;
+        self.pending_claimed_fees = 0;
+        self.positions_claimed = 0;
+        claimed
+    }
+
+    /// Move the carry-over into the current day's investor pool; whatever is not
+    /// paid out today flows back into `carry_over`
+    pub fn take_carry_over(&mut self) {
+        self.day_carry_in = self.carry_over;
+        self.carry_over = 0;
+    }
+
+    /// Day funds not yet paid out or carried over; errors if outflows exceed inflows
+    pub fn day_remaining(&self) -> Result<u64> {
+        self.day_claimed_fees
+            .checked_add(self.day_carry_in)
+            .and_then(|inflow| inflow.checked_sub(self.day_investor_total))
+            .and_then(|remaining| remaining.checked_sub(self.day_carried))
+            .and_then(|remaining| remaining.checked_sub(self.day_ata_reimbursed))
+            .ok_or(FeeRouterError::DistributionExceedsInflow.into())
+    }
+
+    /// Assign a page its share of the day's investor pool, following its snapshotted share of
+    /// the day's locked total (never more than is still unassigned)
+    pub fn allocate_page_share(&mut self, page_locked: u64) -> Result<u64> {
+        let unallocated_pool = self.day_investor_pool.saturating_sub(self.day_pool_allocated);
+        let page_share = mul_div(self.day_investor_pool, page_locked, self.day_total_locked)?
+            .min(unallocated_pool);
+        self.day_pool_allocated += page_share;
+        Ok(page_share)
+    }
+
+    /// Reserve part of the day's funds for investors on a later day
+    pub fn carry_forward(&mut self, amount: u64) -> Result<()> {
+        require!(amount <= self.day_remaining()?, FeeRouterError::DistributionExceedsInflow);
+        self.carry_over = self.carry_over.checked_add(amount).ok_or(FeeRouterError::MathOverflow)?;
+        self.day_carried += amount;
+        Ok(())
+    }
+
+    /// Creator share of the day (claimed fees outside the investor pool) not yet used for
+    /// ATA rent reimbursements
+    pub fn creator_share_unreimbursed(&self) -> u64 {
+        self.day_claimed_fees
+            .saturating_add(self.day_carry_in)
+            .saturating_sub(self.day_investor_pool)
+            .saturating_sub(self.day_ata_reimbursed)
+    }
+
+    /// Record a reimbursement of investor ATA rent paid out of the creator share
+    pub fn reimburse_ata_rent(&mut self, amount: u64) -> Result<()> {
+        require!(
+            amount <= self.creator_share_unreimbursed() && amount <= self.day_remaining()?,
+            FeeRouterError::DistributionExceedsInflow
+        );
+        self.day_ata_reimbursed += amount;
+        Ok(())
+    }
+
+    /// Every investor page of the day has been paid out
+    pub fn all_pages_done(&self) -> bool {
+        self.pages_processed == self.day_page_count
+    }
+
+    /// Release the whole carry-over back into the day's funds (no eligible investors left)
+    pub fn forfeit_carry_over(&mut self) -> u64 {
+        let forfeited = self.carry_over;
+        self.day_carried = self.day_carried.saturating_sub(forfeited);
+        self.carry_over = 0;
+        forfeited
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A freshly opened day with `claimed` fees, `carry_over` from earlier days and the
+    /// investor pool frozen at `investor_share` of the claimed fees plus the carry-in
+    fn open_day(claimed: u64, carry_over: u64, investor_share: u64) -> DistributionState {
+        // An all-zero account body is a valid, empty state
+        let mut state = DistributionState::deserialize(&mut &[0u8; DistributionState::LEN][..]).unwrap();
+        state.current_day = 1;
+        state.day_claimed_fees = claimed;
+        state.carry_over = carry_over;
+        state.take_carry_over();
+        state.day_investor_pool = investor_share + state.day_carry_in;
+        state
+    }
+
+    #[test]
+    fn test_carry_in_joins_day_funds() {
+        let mut state = open_day(1_000, 50, 100);
+        assert_eq!(state.day_carry_in, 50);
+        assert_eq!(state.carry_over, 0);
+        assert_eq!(state.day_remaining().unwrap(), 1_050);
+
+        // Rounding dust is carried for investors, the rest goes to the creator
+        state.day_investor_total += 149;
+        state.carry_forward(1).unwrap();
+        assert_eq!(state.carry_over, 1);
+        assert_eq!(state.day_remaining().unwrap(), 1_050 - 149 - 1);
+    }
+
+    #[test]
+    fn test_page_shares_and_rounding() {
+        // Three equally locked pages split the 150 pool; nothing is lost to rounding
+        let mut state = open_day(1_000, 50, 100);
+        state.day_total_locked = 3;
+        let shares: Vec<u64> = (0..3).map(|_| state.allocate_page_share(1).unwrap()).collect();
+        assert_eq!(shares, vec![50, 50, 50]);
+
+        let mut state = open_day(1_000, 0, 100);
+        state.day_total_locked = 3;
+        for _ in 0..3 {
+            let share = state.allocate_page_share(1).unwrap();
+            assert_eq!(share, 33);
+            state.day_investor_total += share;
+        }
+
+        // The unallocated remainder is carried for investors, the rest goes to the creator
+        let unallocated = state.day_investor_pool - state.day_pool_allocated;
+        assert_eq!(unallocated, 1);
+        state.carry_forward(unallocated).unwrap();
+        assert_eq!(state.carry_over, 1);
+        assert_eq!(state.day_remaining().unwrap(), 1_000 - 99 - 1);
     }
 
-    pub fn is_page_done(&self, page: u32) -> bool {
-        if page >= 128 { return false; }
-        let bit = 1u128 << page;
-        (self.pages_done_mask & bit) != 0
+    #[test]
+    fn test_cap_overflow_is_carried_forward() {
+        let mut state = open_day(1_000, 0, 500);
+
+        // A payout over the cap stays with investors instead of being paid
+        state.day_investor_total += 300;
+        state.carry_forward(200).unwrap();
+        assert_eq!(state.carry_over, 200);
+        assert_eq!(state.day_carried, 200);
+        assert_eq!(state.day_remaining().unwrap(), 500);
+
+        // More than is left of the day cannot be carried
+        assert_eq!(
+            state.carry_forward(501).unwrap_err(),
+            FeeRouterError::DistributionExceedsInflow.into()
+        );
     }
 
-    pub fn mark_page_done(&mut self, page: u32) {
-        if page >= 128 { return; }
-        let bit = 1u128 << page;
-        self.pages_done_mask |= bit;
+    #[test]
+    fn test_forfeit_carry_over() {
+        let mut state = open_day(1_000, 300, 0);
+        state.carry_forward(state.day_carry_in).unwrap();
+        assert_eq!(state.day_remaining().unwrap(), 1_000);
+
+        // With no locked investors the carry-over returns to the day for the creator
+        assert_eq!(state.forfeit_carry_over(), 300);
+        assert_eq!(state.carry_over, 0);
+        assert_eq!(state.day_carried, 0);
+        assert_eq!(state.day_remaining().unwrap(), 1_300);
+    }
+
+    #[test]
+    fn test_reimburse_ata_rent_from_creator_share() {
+        let mut state = open_day(1_000, 0, 900);
+        assert_eq!(state.creator_share_unreimbursed(), 100);
+
+        state.reimburse_ata_rent(60).unwrap();
+        assert_eq!(state.creator_share_unreimbursed(), 40);
+        assert_eq!(state.day_remaining().unwrap(), 940);
+
+        // Reimbursements never dip into the investor pool
+        assert_eq!(
+            state.reimburse_ata_rent(41).unwrap_err(),
+            FeeRouterError::DistributionExceedsInflow.into()
+        );
+        state.reimburse_ata_rent(40).unwrap();
+        assert_eq!(state.creator_share_unreimbursed(), 0);
+    }
+
+    #[test]
+    fn test_day_remaining_rejects_over_distribution() {
+        let mut state = open_day(1_000, 0, 1_000);
+        state.day_investor_total = 1_000;
+        assert_eq!(state.day_remaining().unwrap(), 0);
+
+        state.day_investor_total = 1_001;
+        assert_eq!(
+            state.day_remaining().unwrap_err(),
+            FeeRouterError::DistributionExceedsInflow.into()
+        );
     }
 }
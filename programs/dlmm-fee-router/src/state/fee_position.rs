@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+/// One honorary DLMM position registered on a vault
+#[account]
+pub struct FeePositionRecord {
+    /// Associated vault
+    pub vault: Pubkey,
+
+    /// Position index within the vault (PDA seed)
+    pub index: u32,
+
+    /// The honorary position pubkey
+    pub position: Pubkey,
+
+    /// The DLMM pool the position belongs to
+    pub pool: Pubkey,
+
+    /// Bin range of the position (inclusive)
+    pub lower_bin_id: i32,
+    pub upper_bin_id: i32,
+
+    /// Claim round (`current_day + 1` at claim time) the position was last claimed for
+    pub claimed_for_day: u64,
+
+    /// Quote fees claimed from this position over its lifetime
+    pub total_claimed: u64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl FeePositionRecord {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // vault
+        4 + // index
+        32 + // position
+        32 + // pool
+        4 + // lower_bin_id
+        4 + // upper_bin_id
+        8 + // claimed_for_day
+        8 + // total_claimed
+        1; // bump
+}
@@ -30,8 +30,11 @@ pub struct InvestorRecord {
     /// Bump seed for PDA derivation
     pub bump: u8,
     
+    /// Fees credited in accrual mode and not yet claimed
+    pub fees_owed: u64,
+    
     /// Reserved space for future upgrades
-    pub _reserved: [u8; 32],
+    pub _reserved: [u8; 24],
 }
 
 impl InvestorRecord {
@@ -45,7 +48,8 @@ impl InvestorRecord {
         4 + // page
         4 + // page_index
         1 + // bump
-        32; // _reserved
+        8 + // fees_owed
+        24; // _reserved
 }
 
 /// Aggregated investor data for a page
@@ -66,6 +70,9 @@ pub struct InvestorPage {
     /// Total locked amount for this page (cached for efficiency)
     pub total_locked: u64,
     
+    /// Distribution snapshot `total_locked` was taken at
+    pub snapshot_ts: i64,
+    
     /// Last update timestamp
     pub last_update_ts: i64,
     
@@ -80,6 +87,7 @@ impl InvestorPage {
         4 + // investor_count
         4 + // Vec length prefix
         8 + // total_locked
+        8 + // snapshot_ts
         8 + // last_update_ts
         1; // bump
     
@@ -1,7 +1,11 @@
 pub mod vault;
 pub mod distribution;
 pub mod investor;
+pub mod page_bitmap;
+pub mod fee_position;
 
 pub use vault::*;
 pub use distribution::*;
 pub use investor::*;
+pub use page_bitmap::*;
+pub use fee_position::*;
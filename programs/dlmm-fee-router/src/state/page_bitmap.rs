@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::PAGES_PER_BITMAP_CHUNK, errors::FeeRouterError};
+
+/// Processed investor pages for one chunk of `PAGES_PER_BITMAP_CHUNK` pages.
+/// Bits belong to `day`; a chunk left over from an earlier day reads as empty.
+#[account]
+pub struct PageBitmap {
+    /// Associated vault
+    pub vault: Pubkey,
+    
+    /// Chunk index (pages `chunk * PAGES_PER_BITMAP_CHUNK ..`)
+    pub chunk: u32,
+    
+    /// Distribution day the bits refer to
+    pub day: u64,
+    
+    /// One bit per page in the chunk
+    pub bits: [u8; 128],
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl PageBitmap {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // vault
+        4 + // chunk
+        8 + // day
+        128 + // bits
+        1; // bump
+
+    /// Chunk index covering `page`
+    pub fn chunk_for(page: u32) -> u32 {
+        page / PAGES_PER_BITMAP_CHUNK
+    }
+
+    /// Byte index and bit mask of `page` within this chunk
+    fn bit(&self, page: u32) -> Result<(usize, u8)> {
+        require!(Self::chunk_for(page) == self.chunk, FeeRouterError::InvalidPageNumber);
+        let offset = (page % PAGES_PER_BITMAP_CHUNK) as usize;
+        Ok((offset / 8, 1u8 << (offset % 8)))
+    }
+
+    /// Record `page` as processed for `day`, failing if it already was
+    pub fn mark_page_done(&mut self, day: u64, page: u32) -> Result<()> {
+        let (byte, mask) = self.bit(page)?;
+        if self.day != day {
+            self.day = day;
+            self.bits = [0u8; 128];
+        }
+        require!(self.bits[byte] & mask == 0, FeeRouterError::PageAlreadyProcessed);
+        self.bits[byte] |= mask;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bitmap(chunk: u32, day: u64) -> PageBitmap {
+        PageBitmap {
+            vault: Pubkey::new_unique(),
+            chunk,
+            day,
+            bits: [0u8; 128],
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_duplicate_page_rejected() {
+        let mut bitmap = bitmap(0, 1);
+        bitmap.mark_page_done(1, 7).unwrap();
+        assert_eq!(bitmap.bits[0], 1 << 7);
+
+        assert_eq!(
+            bitmap.mark_page_done(1, 7).unwrap_err(),
+            FeeRouterError::PageAlreadyProcessed.into()
+        );
+        bitmap.mark_page_done(1, 8).unwrap();
+        assert_eq!(bitmap.bits[1], 1);
+    }
+
+    #[test]
+    fn test_new_day_resets_chunk() {
+        let mut bitmap = bitmap(0, 1);
+        bitmap.mark_page_done(1, 0).unwrap();
+        bitmap.mark_page_done(1, 1).unwrap();
+
+        // Bits left over from an earlier day are cleared on the next day's first mark
+        bitmap.mark_page_done(2, 0).unwrap();
+        assert_eq!(bitmap.day, 2);
+        assert_eq!(bitmap.bits[0], 1);
+        bitmap.mark_page_done(2, 1).unwrap();
+    }
+
+    #[test]
+    fn test_page_outside_chunk_rejected() {
+        let mut bitmap = bitmap(1, 1);
+        assert_eq!(PageBitmap::chunk_for(PAGES_PER_BITMAP_CHUNK), 1);
+        bitmap.mark_page_done(1, PAGES_PER_BITMAP_CHUNK).unwrap();
+        bitmap.mark_page_done(1, 2 * PAGES_PER_BITMAP_CHUNK - 1).unwrap();
+
+        for page in [0, PAGES_PER_BITMAP_CHUNK - 1, 2 * PAGES_PER_BITMAP_CHUNK] {
+            assert_eq!(
+                bitmap.mark_page_done(1, page).unwrap_err(),
+                FeeRouterError::InvalidPageNumber.into()
+            );
+        }
+    }
+}
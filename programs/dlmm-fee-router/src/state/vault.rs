@@ -1,22 +1,33 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{MIN_DISTRIBUTION_INTERVAL_SECS, MAX_DISTRIBUTION_INTERVAL_SECS, SECONDS_PER_DAY};
+
 #[account]
 #[derive(Default)]
 pub struct Vault {
     /// Unique vault identifier
     pub vault_id: [u8; 32],
     
+    /// Admin allowed to manage the vault configuration and investors
+    pub admin: Pubkey,
+    
+    /// Admin proposed via `propose_admin`, pending acceptance
+    pub pending_admin: Option<Pubkey>,
+    
+    /// Optional key allowed to pause and unpause the vault alongside the admin
+    pub guardian: Option<Pubkey>,
+    
+    /// Distribution, registration and position setup are halted while set
+    pub paused: bool,
+    
     /// The creator wallet that receives remainder fees
     pub creator_wallet: Pubkey,
     
-    /// The DLMM pool pubkey
-    pub pool: Pubkey,
-    
     /// The quote mint (usually USDC)
     pub quote_mint: Pubkey,
     
-    /// The honorary position pubkey
-    pub fee_position: Pubkey,
+    /// The base mint (the vested token held in investor streams)
+    pub base_mint: Pubkey,
     
     /// Investor fee share in basis points (max 10000)
     pub investor_fee_share_bps: u16,
@@ -27,9 +38,36 @@ pub struct Vault {
     /// Optional daily distribution cap
     pub daily_cap_lamports: Option<u64>,
     
+    /// Quote amount paid to the crank, out of the creator share, per investor ATA it creates
+    pub ata_rent_reimbursement: u64,
+    
+    /// Close zero-fee days as a no-op (rolling carry-over forward) instead of failing
+    pub roll_over_empty_days: bool,
+    
+    /// Credit investor payouts to their records for later claiming instead of transferring
+    pub accrual_mode: bool,
+    
+    /// Minimum time between distribution days in rolling-window mode
+    pub distribution_interval_secs: i64,
+    
+    /// Fixed UTC-aligned distribution epochs; `None` keeps the rolling window
+    pub epoch: Option<EpochConfig>,
+    
+    /// Config change waiting for its timelock to elapse
+    pub pending_config: Option<PendingVaultConfig>,
+    
     /// Total initial allocation for investors (Y0)
     pub total_investor_allocation: u64,
 
+    /// Number of registered investors (drives page assignment)
+    pub investor_count: u32,
+    
+    /// Number of investor pages opened by registration
+    pub investor_page_count: u32,
+    
+    /// Number of honorary positions registered (one `FeePositionRecord` each)
+    pub position_count: u32,
+
     /// Treasury ATAs for quote and base (base used only for invariant checks)
     pub treasury_quote: Pubkey,
     pub treasury_base: Pubkey,
@@ -37,31 +75,202 @@ pub struct Vault {
     /// Is the vault initialized
     pub is_initialized: bool,
     
-    /// Is the fee position created
+    /// Is at least one fee position created
     pub position_initialized: bool,
     
     /// Bump seed for PDA derivation
     pub bump: u8,
     
     /// Reserved space for future upgrades
-    pub _reserved: [u8; 32],
+    pub _reserved: [u8; 16],
 }
 
 impl Vault {
     pub const LEN: usize = 8 + // discriminator
         32 + // vault_id
+        32 + // admin
+        1 + 32 + // Option<pending_admin>
+        1 + 32 + // Option<guardian>
+        1 + // paused
         32 + // creator_wallet
-        32 + // pool
         32 + // quote_mint
-        32 + // fee_position
+        32 + // base_mint
         2 + // investor_fee_share_bps
         8 + // min_payout_lamports
         1 + 8 + // Option<daily_cap_lamports>
+        8 + // ata_rent_reimbursement
+        1 + // roll_over_empty_days
+        1 + // accrual_mode
+        8 + // distribution_interval_secs
+        1 + 8 + 8 + // Option<epoch>
+        1 + 32 + 2 + 8 + 1 + 8 + 8 + 8 + // Option<pending_config>
         8 + // total_investor_allocation
+        4 + // investor_count
+        4 + // investor_page_count
+        4 + // position_count
         32 + // treasury_quote
         32 + // treasury_base
         1 + // is_initialized
         1 + // position_initialized
         1 + // bump
-        32; // _reserved
+        16; // _reserved
+
+    /// Admin or guardian, the keys allowed to pause and unpause the vault
+    pub fn can_pause(&self, authority: &Pubkey) -> bool {
+        self.admin == *authority || self.guardian == Some(*authority)
+    }
+
+    /// Admin-adjustable distribution parameters
+    pub fn config(&self) -> VaultConfig {
+        VaultConfig {
+            creator_wallet: self.creator_wallet,
+            investor_fee_share_bps: self.investor_fee_share_bps,
+            min_payout_lamports: self.min_payout_lamports,
+            daily_cap_lamports: self.daily_cap_lamports,
+            ata_rent_reimbursement: self.ata_rent_reimbursement,
+        }
+    }
+
+    pub fn apply_config(&mut self, config: &VaultConfig) {
+        self.creator_wallet = config.creator_wallet;
+        self.investor_fee_share_bps = config.investor_fee_share_bps;
+        self.min_payout_lamports = config.min_payout_lamports;
+        self.daily_cap_lamports = config.daily_cap_lamports;
+        self.ata_rent_reimbursement = config.ata_rent_reimbursement;
+    }
+
+    /// Length of one distribution period in seconds
+    pub fn distribution_period_secs(&self) -> i64 {
+        self.epoch.map_or(self.distribution_interval_secs, |epoch| epoch.period_secs)
+    }
+
+    /// Daily cap scaled to the vault's distribution period
+    pub fn period_cap_lamports(&self) -> Option<u64> {
+        self.daily_cap_lamports.map(|cap| {
+            let scaled = cap as u128 * self.distribution_period_secs() as u128
+                / SECONDS_PER_DAY as u128;
+            u64::try_from(scaled).unwrap_or(u64::MAX)
+        })
+    }
+}
+
+/// Distribution parameters the admin can change after initialization
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VaultConfig {
+    pub creator_wallet: Pubkey,
+    pub investor_fee_share_bps: u16,
+    pub min_payout_lamports: u64,
+    pub daily_cap_lamports: Option<u64>,
+    pub ata_rent_reimbursement: u64,
+}
+
+/// Parameters a vault is created with
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InitializeVaultParams {
+    pub creator_wallet: Pubkey,
+    pub investor_fee_share_bps: u16,
+    pub min_payout_lamports: u64,
+    pub daily_cap_lamports: Option<u64>,
+    pub roll_over_empty_days: bool,
+    pub accrual_mode: bool,
+    pub distribution_interval_secs: i64,
+    pub epoch: Option<EpochConfig>,
+}
+
+/// Queued config change and the earliest time it may be executed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PendingVaultConfig {
+    pub config: VaultConfig,
+    pub effective_ts: i64,
+}
+
+/// Fixed distribution epochs: epoch index = floor((ts - offset_secs) / period_secs)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EpochConfig {
+    /// Epoch length in seconds
+    pub period_secs: i64,
+    
+    /// Shift of the epoch boundaries from the Unix epoch (UTC midnight when 0)
+    pub offset_secs: i64,
+}
+
+impl EpochConfig {
+    pub fn is_valid(&self) -> bool {
+        (MIN_DISTRIBUTION_INTERVAL_SECS..=MAX_DISTRIBUTION_INTERVAL_SECS).contains(&self.period_secs)
+            && (0..self.period_secs).contains(&self.offset_secs)
+    }
+
+    /// Epoch index containing `ts`
+    pub fn epoch_index(&self, ts: i64) -> u64 {
+        ts.saturating_sub(self.offset_secs)
+            .div_euclid(self.period_secs)
+            .max(0) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_epoch_index_offset_boundaries() {
+        // Calendar days starting at 06:00 UTC
+        let epoch = EpochConfig { period_secs: SECONDS_PER_DAY, offset_secs: 6 * 3600 };
+        let day_10 = 10 * SECONDS_PER_DAY + epoch.offset_secs;
+
+        assert_eq!(epoch.epoch_index(day_10 - 1), 9);
+        assert_eq!(epoch.epoch_index(day_10), 10);
+        assert_eq!(epoch.epoch_index(day_10 + SECONDS_PER_DAY - 1), 10);
+        assert_eq!(epoch.epoch_index(day_10 + SECONDS_PER_DAY), 11);
+
+        // Timestamps before the first boundary clamp to epoch 0
+        assert_eq!(epoch.epoch_index(0), 0);
+        assert_eq!(epoch.epoch_index(-SECONDS_PER_DAY), 0);
+    }
+
+    #[test]
+    fn test_epoch_config_validation() {
+        let epoch = |period_secs, offset_secs| EpochConfig { period_secs, offset_secs };
+
+        assert!(epoch(SECONDS_PER_DAY, 0).is_valid());
+        assert!(epoch(SECONDS_PER_DAY, SECONDS_PER_DAY - 1).is_valid());
+        assert!(epoch(MIN_DISTRIBUTION_INTERVAL_SECS, 0).is_valid());
+        assert!(epoch(MAX_DISTRIBUTION_INTERVAL_SECS, 0).is_valid());
+
+        // The offset must fall inside one period
+        assert!(!epoch(SECONDS_PER_DAY, SECONDS_PER_DAY).is_valid());
+        assert!(!epoch(SECONDS_PER_DAY, SECONDS_PER_DAY + 1).is_valid());
+        assert!(!epoch(SECONDS_PER_DAY, -1).is_valid());
+
+        // The period must stay within the distribution interval bounds
+        assert!(!epoch(MIN_DISTRIBUTION_INTERVAL_SECS - 1, 0).is_valid());
+        assert!(!epoch(MAX_DISTRIBUTION_INTERVAL_SECS + 1, 0).is_valid());
+    }
+
+    #[test]
+    fn test_period_cap_scales_with_period() {
+        let mut vault = Vault {
+            daily_cap_lamports: Some(2_400),
+            distribution_interval_secs: SECONDS_PER_DAY,
+            ..Default::default()
+        };
+        assert_eq!(vault.period_cap_lamports(), Some(2_400));
+
+        // Hourly and weekly rolling windows
+        vault.distribution_interval_secs = 3600;
+        assert_eq!(vault.period_cap_lamports(), Some(100));
+        vault.distribution_interval_secs = 7 * SECONDS_PER_DAY;
+        assert_eq!(vault.period_cap_lamports(), Some(16_800));
+
+        // Aligned epochs take precedence over the rolling interval
+        vault.epoch = Some(EpochConfig { period_secs: 3600, offset_secs: 0 });
+        assert_eq!(vault.period_cap_lamports(), Some(100));
+
+        // Scaling saturates instead of overflowing, and no cap stays no cap
+        vault.epoch = None;
+        vault.daily_cap_lamports = Some(u64::MAX);
+        assert_eq!(vault.period_cap_lamports(), Some(u64::MAX));
+        vault.daily_cap_lamports = None;
+        assert_eq!(vault.period_cap_lamports(), None);
+    }
 }
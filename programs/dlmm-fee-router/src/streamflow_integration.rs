@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::STREAMFLOW_PROGRAM_ID, errors::FeeRouterError};
+
+/// Streamflow vesting contract (leading fields of the on-chain `Contract` account)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct StreamflowContract {
+    pub magic: u64,
+    pub version: u8,
+    pub created_at: u64,
+    pub amount_withdrawn: u64,
+    pub canceled_at: u64,
+    pub end_time: u64,
+    pub last_withdrawn_at: u64,
+    pub sender: Pubkey,
+    pub sender_tokens: Pubkey,
+    pub recipient: Pubkey,
+    pub recipient_tokens: Pubkey,
+    pub mint: Pubkey,
+    pub escrow_tokens: Pubkey,
+    pub streamflow_treasury: Pubkey,
+    pub streamflow_treasury_tokens: Pubkey,
+    pub streamflow_fee_total: u64,
+    pub streamflow_fee_withdrawn: u64,
+    pub streamflow_fee_percent: f32,
+    pub partner: Pubkey,
+    pub partner_tokens: Pubkey,
+    pub partner_fee_total: u64,
+    pub partner_fee_withdrawn: u64,
+    pub partner_fee_percent: f32,
+    pub ix: CreateParams,
+}
+
+/// Vesting schedule parameters the stream was created with
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CreateParams {
+    pub start_time: u64,
+    pub net_amount_deposited: u64,
+    pub period: u64,
+    pub amount_per_period: u64,
+    pub cliff: u64,
+    pub cliff_amount: u64,
+    pub cancelable_by_sender: bool,
+    pub cancelable_by_recipient: bool,
+    pub automatic_withdrawal: bool,
+    pub transferable_by_sender: bool,
+    pub transferable_by_recipient: bool,
+    pub can_topup: bool,
+    pub stream_name: [u8; 64],
+}
+
+impl Default for CreateParams {
+    fn default() -> Self {
+        Self {
+            start_time: 0,
+            net_amount_deposited: 0,
+            period: 0,
+            amount_per_period: 0,
+            cliff: 0,
+            cliff_amount: 0,
+            cancelable_by_sender: false,
+            cancelable_by_recipient: false,
+            automatic_withdrawal: false,
+            transferable_by_sender: false,
+            transferable_by_recipient: false,
+            can_topup: false,
+            stream_name: [0u8; 64],
+        }
+    }
+}
+
+impl StreamflowContract {
+    /// A cancelled stream has released its escrow, so nothing remains locked
+    pub fn is_cancelled(&self) -> bool {
+        self.canceled_at != 0
+    }
+
+    /// Amount unlocked by the vesting schedule at `current_ts`
+    pub fn unlocked_amount_at(&self, current_ts: u64) -> u64 {
+        let deposited = self.ix.net_amount_deposited;
+        let cliff = self.ix.cliff.max(self.ix.start_time);
+
+        let scheduled = if current_ts < cliff {
+            0
+        } else if current_ts >= self.end_time || self.ix.period == 0 {
+            deposited
+        } else {
+            let periods = (current_ts - cliff) / self.ix.period;
+            self.ix.cliff_amount
+                .saturating_add(periods.saturating_mul(self.ix.amount_per_period))
+        };
+
+        // Withdrawn tokens are unlocked even if the schedule says otherwise
+        scheduled.max(self.amount_withdrawn).min(deposited)
+    }
+
+    /// Amount still locked in the stream at `current_ts`
+    pub fn locked_amount_at(&self, current_ts: u64) -> u64 {
+        if self.is_cancelled() {
+            return 0;
+        }
+        self.ix.net_amount_deposited
+            .saturating_sub(self.unlocked_amount_at(current_ts))
+    }
+}
+
+/// Decode a Streamflow contract account, checking the owning program
+pub fn deserialize_contract(stream: &AccountInfo) -> Result<StreamflowContract> {
+    require_keys_eq!(
+        *stream.owner,
+        STREAMFLOW_PROGRAM_ID,
+        FeeRouterError::InvalidStreamAccount
+    );
+
+    let data = stream.try_borrow_data()?;
+    StreamflowContract::deserialize(&mut &data[..])
+        .map_err(|_| FeeRouterError::InvalidStreamAccount.into())
+}
+
+/// Read the still-locked amount of a stream for the given recipient and vested mint
+pub fn read_locked_amount(
+    stream: &AccountInfo,
+    recipient: &Pubkey,
+    mint: &Pubkey,
+    current_ts: i64,
+) -> Result<u64> {
+    let contract = deserialize_contract(stream)?;
+
+    require_keys_eq!(contract.recipient, *recipient, FeeRouterError::InvalidStreamAccount);
+    require_keys_eq!(contract.mint, *mint, FeeRouterError::InvalidStreamAccount);
+
+    Ok(contract.locked_amount_at(current_ts.max(0) as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const START: u64 = 1_700_000_000;
+    const PERIOD: u64 = 86_400;
+
+    /// 1000 tokens: 100 at a 30-day cliff, then 10 per day for 90 days
+    fn vesting_contract() -> StreamflowContract {
+        let cliff = START + 30 * PERIOD;
+        StreamflowContract {
+            end_time: cliff + 90 * PERIOD,
+            recipient: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            ix: CreateParams {
+                start_time: START,
+                net_amount_deposited: 1_000,
+                period: PERIOD,
+                amount_per_period: 10,
+                cliff,
+                cliff_amount: 100,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_locked_before_cliff() {
+        let contract = vesting_contract();
+        assert_eq!(contract.locked_amount_at(START), 1_000);
+        assert_eq!(contract.locked_amount_at(contract.ix.cliff - 1), 1_000);
+    }
+
+    #[test]
+    fn test_locked_mid_vest() {
+        let contract = vesting_contract();
+        assert_eq!(contract.locked_amount_at(contract.ix.cliff), 900);
+
+        // Partial periods don't unlock anything extra
+        let ts = contract.ix.cliff + 45 * PERIOD + PERIOD / 2;
+        assert_eq!(contract.locked_amount_at(ts), 1_000 - 100 - 450);
+
+        // Withdrawals can't exceed unlocked, but are never counted as locked
+        let mut withdrawn = contract.clone();
+        withdrawn.amount_withdrawn = 700;
+        assert_eq!(withdrawn.locked_amount_at(ts), 300);
+    }
+
+    #[test]
+    fn test_locked_fully_vested() {
+        let contract = vesting_contract();
+        assert_eq!(contract.locked_amount_at(contract.end_time), 0);
+        assert_eq!(contract.locked_amount_at(contract.end_time + 365 * PERIOD), 0);
+    }
+
+    #[test]
+    fn test_locked_cancelled() {
+        let mut contract = vesting_contract();
+        contract.canceled_at = contract.ix.cliff + PERIOD;
+        assert_eq!(contract.locked_amount_at(START), 0);
+        assert_eq!(contract.locked_amount_at(contract.ix.cliff + 10 * PERIOD), 0);
+    }
+
+    #[test]
+    fn test_contract_round_trip() {
+        let contract = vesting_contract();
+        let mut data = contract.try_to_vec().unwrap();
+        // Trailing fields of the on-chain account are ignored
+        data.extend_from_slice(&[0u8; 128]);
+
+        let decoded = StreamflowContract::deserialize(&mut &data[..]).unwrap();
+        assert_eq!(decoded.recipient, contract.recipient);
+        assert_eq!(decoded.mint, contract.mint);
+        assert_eq!(decoded.ix.cliff, contract.ix.cliff);
+        assert_eq!(decoded.locked_amount_at(contract.ix.cliff), 900);
+    }
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{self, extension::transfer_fee::TransferFeeConfig};
+use anchor_spl::token_interface::{
+    self, get_mint_extension_data, HarvestWithheldTokensToMint, Mint, TransferChecked,
+};
+
+use crate::errors::FeeRouterError;
+
+/// Fee withheld by the mint on a transfer of `amount` in the current epoch
+/// (zero for legacy SPL mints and Token-2022 mints without the transfer-fee extension)
+pub fn transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(0);
+    }
+    match get_mint_extension_data::<TransferFeeConfig>(mint) {
+        Ok(config) => epoch_transfer_fee(&config, Clock::get()?.epoch, amount),
+        Err(_) => Ok(0),
+    }
+}
+
+/// Fee charged under `config` on a transfer of `amount` during `epoch`
+fn epoch_transfer_fee(config: &TransferFeeConfig, epoch: u64, amount: u64) -> Result<u64> {
+    config
+        .calculate_epoch_fee(epoch, amount)
+        .ok_or(FeeRouterError::MathOverflow.into())
+}
+
+/// Amount the recipient is credited with once the mint's transfer fee is withheld
+pub fn net_of_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    Ok(amount.saturating_sub(transfer_fee(mint, amount)?))
+}
+
+/// Move `amount` out of a PDA-owned treasury with `transfer_checked`.
+/// Returns the transfer fee withheld from the recipient.
+pub fn transfer_from_treasury<'info>(
+    token_program: AccountInfo<'info>,
+    treasury: AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    destination: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<u64> {
+    let fee = transfer_fee(&mint.to_account_info(), amount)?;
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program,
+            TransferChecked {
+                from: treasury,
+                mint: mint.to_account_info(),
+                to: destination,
+                authority,
+            },
+            signer_seeds,
+        ),
+        amount,
+        mint.decimals,
+    )?;
+
+    Ok(fee)
+}
+
+/// Move fees withheld on a Token-2022 account to its mint so the account can be closed.
+/// Permissionless; a no-op for legacy SPL mints and mints without the transfer-fee extension.
+pub fn harvest_withheld_fees<'info>(
+    token_program: AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_account: AccountInfo<'info>,
+) -> Result<()> {
+    let mint_info = mint.to_account_info();
+    if *mint_info.owner != spl_token_2022::ID
+        || get_mint_extension_data::<TransferFeeConfig>(&mint_info).is_err()
+    {
+        return Ok(());
+    }
+
+    token_interface::harvest_withheld_tokens_to_mint(
+        CpiContext::new(
+            token_program.clone(),
+            HarvestWithheldTokensToMint {
+                token_program_id: token_program,
+                mint: mint_info,
+            },
+        ),
+        vec![token_account],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFee;
+
+    fn transfer_fee_config(older_bps: u16, newer_bps: u16, newer_epoch: u64, max_fee: u64) -> TransferFeeConfig {
+        let fee = |epoch: u64, bps: u16| TransferFee {
+            epoch: epoch.into(),
+            maximum_fee: max_fee.into(),
+            transfer_fee_basis_points: bps.into(),
+        };
+        TransferFeeConfig {
+            transfer_fee_config_authority: Default::default(),
+            withdraw_withheld_authority: Default::default(),
+            withheld_amount: 0u64.into(),
+            older_transfer_fee: fee(0, older_bps),
+            newer_transfer_fee: fee(newer_epoch, newer_bps),
+        }
+    }
+
+    #[test]
+    fn test_epoch_transfer_fee() {
+        let config = transfer_fee_config(100, 200, 10, 50);
+
+        // Older fee applies until the newer one's epoch
+        assert_eq!(epoch_transfer_fee(&config, 5, 1_000).unwrap(), 10);
+        assert_eq!(epoch_transfer_fee(&config, 10, 1_000).unwrap(), 20);
+
+        // Fees round up and are capped at the maximum
+        assert_eq!(epoch_transfer_fee(&config, 10, 1).unwrap(), 1);
+        assert_eq!(epoch_transfer_fee(&config, 10, 1_000_000).unwrap(), 50);
+        assert_eq!(epoch_transfer_fee(&config, 10, 0).unwrap(), 0);
+    }
+}
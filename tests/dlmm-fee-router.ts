@@ -11,6 +11,7 @@ import {
 } from "@solana/web3.js";
 import {
   TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
   ASSOCIATED_TOKEN_PROGRAM_ID,
   createMint,
   mintTo,
@@ -158,10 +159,16 @@ describe("dlmm-fee-router", () => {
       const tx = await program.methods
         .initializeVault(
           Array.from(vaultId),
-          creatorWallet.publicKey,
-          INVESTOR_FEE_SHARE_BPS,
-          new BN(MIN_PAYOUT_LAMPORTS),
-          DAILY_CAP_LAMPORTS
+          {
+            creatorWallet: creatorWallet.publicKey,
+            investorFeeShareBps: INVESTOR_FEE_SHARE_BPS,
+            minPayoutLamports: new BN(MIN_PAYOUT_LAMPORTS),
+            dailyCapLamports: DAILY_CAP_LAMPORTS,
+            rollOverEmptyDays: true, // roll over zero-fee days
+            accrualMode: false, // transfer payouts directly
+            distributionIntervalSecs: new BN(86400), // daily distributions
+            epoch: null, // rolling window
+          }
         )
         .accounts({
           vault,
@@ -174,6 +181,7 @@ describe("dlmm-fee-router", () => {
           authority: provider.wallet.publicKey,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
+          baseTokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         })
@@ -213,10 +221,16 @@ describe("dlmm-fee-router", () => {
         await program.methods
           .initializeVault(
             Array.from(vaultId),
-            creatorWallet.publicKey,
-            INVESTOR_FEE_SHARE_BPS,
-            new BN(MIN_PAYOUT_LAMPORTS),
-            DAILY_CAP_LAMPORTS
+            {
+              creatorWallet: creatorWallet.publicKey,
+              investorFeeShareBps: INVESTOR_FEE_SHARE_BPS,
+              minPayoutLamports: new BN(MIN_PAYOUT_LAMPORTS),
+              dailyCapLamports: DAILY_CAP_LAMPORTS,
+              rollOverEmptyDays: true,
+              accrualMode: false,
+              distributionIntervalSecs: new BN(86400),
+              epoch: null,
+            }
           )
           .accounts({
             vault,
@@ -229,6 +243,7 @@ describe("dlmm-fee-router", () => {
             authority: provider.wallet.publicKey,
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
+            baseTokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             rent: anchor.web3.SYSVAR_RENT_PUBKEY,
           })
@@ -270,10 +285,16 @@ describe("dlmm-fee-router", () => {
         await program.methods
           .initializeVault(
             Array.from(newVaultId),
-            creatorWallet.publicKey,
-            10001, // Invalid: > 10000
-            new BN(MIN_PAYOUT_LAMPORTS),
-            null
+            {
+              creatorWallet: creatorWallet.publicKey,
+              investorFeeShareBps: 10001, // Invalid: > 10000
+              minPayoutLamports: new BN(MIN_PAYOUT_LAMPORTS),
+              dailyCapLamports: null,
+              rollOverEmptyDays: false,
+              accrualMode: false,
+              distributionIntervalSecs: new BN(86400),
+              epoch: null,
+            }
           )
           .accounts({
             vault: newVault,
@@ -286,6 +307,7 @@ describe("dlmm-fee-router", () => {
             authority: provider.wallet.publicKey,
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
+            baseTokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             rent: anchor.web3.SYSVAR_RENT_PUBKEY,
           })
@@ -296,6 +318,81 @@ describe("dlmm-fee-router", () => {
         assert.include(error.toString(), "InvalidFeeShareBps");
       }
     });
+    
+    it("Should initialize a vault with a Token-2022 quote mint and a legacy base mint", async () => {
+      const newVaultId = Buffer.from(Array.from({ length: 32 }, () => 22));
+      const [newVault] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vault"), newVaultId],
+        program.programId
+      );
+      const [newDistState] = PublicKey.findProgramAddressSync(
+        [Buffer.from("distribution_state"), newVaultId],
+        program.programId
+      );
+      const [newFeeOwner] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vault"), newVaultId, Buffer.from("investor_fee_pos_owner")],
+        program.programId
+      );
+      
+      const quoteMint2022 = await createMint(
+        provider.connection,
+        creatorWallet,
+        creatorWallet.publicKey,
+        null,
+        6,
+        undefined,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const newTreasuryQuote = await getAssociatedTokenAddress(
+        quoteMint2022,
+        newFeeOwner,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const newTreasuryBase = await getAssociatedTokenAddress(
+        baseMint,
+        newFeeOwner,
+        true,
+        TOKEN_PROGRAM_ID
+      );
+      
+      await program.methods
+        .initializeVault(
+          Array.from(newVaultId),
+          {
+            creatorWallet: creatorWallet.publicKey,
+            investorFeeShareBps: INVESTOR_FEE_SHARE_BPS,
+            minPayoutLamports: new BN(MIN_PAYOUT_LAMPORTS),
+            dailyCapLamports: null,
+            rollOverEmptyDays: false,
+            accrualMode: false,
+            distributionIntervalSecs: new BN(86400),
+            epoch: null,
+          }
+        )
+        .accounts({
+          vault: newVault,
+          distributionState: newDistState,
+          quoteMint: quoteMint2022,
+          baseMint,
+          treasuryQuote: newTreasuryQuote,
+          treasuryBase: newTreasuryBase,
+          feePositionOwnerPda: newFeeOwner,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          baseTokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .rpc();
+      
+      const vaultAccount = await program.account.vault.fetch(newVault);
+      assert.equal(vaultAccount.quoteMint.toBase58(), quoteMint2022.toBase58());
+      assert.equal(vaultAccount.treasuryQuote.toBase58(), newTreasuryQuote.toBase58());
+      assert.equal(vaultAccount.treasuryBase.toBase58(), newTreasuryBase.toBase58());
+    });
   });
   
   describe("Initialize Fee Position", () => {
@@ -307,10 +404,15 @@ describe("dlmm-fee-router", () => {
       // 3. Verify quote-only fee configuration
       
       // For now, we'll simulate the initialization
+      const [feePositionRecord] = PublicKey.findProgramAddressSync(
+        [Buffer.from("fee_position"), vaultId, new BN(0).toArrayLike(Buffer, "le", 4)],
+        program.programId
+      );
       const tx = await program.methods
         .initializeFeePosition(Array.from(vaultId))
         .accounts({
           vault,
+          feePositionRecord,
           pool: mockPool.publicKey,
           feePositionOwner,
           feePosition: mockFeePosition.publicKey,
@@ -319,25 +421,34 @@ describe("dlmm-fee-router", () => {
           tokenXMint: baseMint,
           tokenYMint: quoteMint,
           quoteMint,
+          eventAuthority: PublicKey.findProgramAddressSync(
+            [Buffer.from("__event_authority")],
+            new PublicKey("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo")
+          )[0],
           dlmmProgram: new PublicKey("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo"),
           authority: provider.wallet.publicKey,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         })
+        .signers([mockFeePosition])
         .rpc();
       
       console.log("Initialize fee position transaction:", tx);
       
-      // Verify vault was updated
+      // Verify the position was registered at index 0
       const vaultAccount = await program.account.vault.fetch(vault);
       assert.isTrue(vaultAccount.positionInitialized);
+      assert.equal(vaultAccount.positionCount, 1);
+      
+      const record = await program.account.feePositionRecord.fetch(feePositionRecord);
+      assert.equal(record.index, 0);
       assert.equal(
-        vaultAccount.feePosition.toBase58(),
+        record.position.toBase58(),
         mockFeePosition.publicKey.toBase58()
       );
       assert.equal(
-        vaultAccount.pool.toBase58(),
+        record.pool.toBase58(),
         mockPool.publicKey.toBase58()
       );
     });
@@ -358,10 +469,16 @@ describe("dlmm-fee-router", () => {
       await program.methods
         .initializeVault(
           Array.from(newVaultId),
-          creatorWallet.publicKey,
-          INVESTOR_FEE_SHARE_BPS,
-          new BN(MIN_PAYOUT_LAMPORTS),
-          null
+          {
+            creatorWallet: creatorWallet.publicKey,
+            investorFeeShareBps: INVESTOR_FEE_SHARE_BPS,
+            minPayoutLamports: new BN(MIN_PAYOUT_LAMPORTS),
+            dailyCapLamports: null,
+            rollOverEmptyDays: false,
+            accrualMode: false,
+            distributionIntervalSecs: new BN(86400),
+            epoch: null,
+          }
         )
         .accounts({
           vault: newVault,
@@ -370,6 +487,7 @@ describe("dlmm-fee-router", () => {
           authority: provider.wallet.publicKey,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
+          baseTokenProgram: TOKEN_PROGRAM_ID,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         })
         .rpc();
@@ -392,6 +510,10 @@ describe("dlmm-fee-router", () => {
           .initializeFeePosition(Array.from(newVaultId))
           .accounts({
             vault: newVault,
+            feePositionRecord: PublicKey.findProgramAddressSync(
+              [Buffer.from("fee_position"), newVaultId, new BN(0).toArrayLike(Buffer, "le", 4)],
+              program.programId
+            )[0],
             pool: mockPool.publicKey,
             feePositionOwner: newFeePositionOwner,
             feePosition: Keypair.generate().publicKey,
@@ -416,31 +538,301 @@ describe("dlmm-fee-router", () => {
   });
   
   describe("Update Investor Data", () => {
-    it("Should update investor allocation data", async () => {
+    it("Should register investors and recompute Y0 from records", async () => {
+      const investors = [investor1, investor2, investor3].map((investor) => ({
+        investor: investor.publicKey,
+        streamPubkey: Keypair.generate().publicKey,
+        initialAllocation: TOTAL_ALLOCATION.divn(3),
+      }));
+      const investorRecords = investors.map(({ investor }) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("investor_record"), vaultId, investor.toBuffer()],
+          program.programId
+        )[0]
+      );
+      const [investorPage] = PublicKey.findProgramAddressSync(
+        [Buffer.from("investor_page"), vaultId, new BN(0).toArrayLike(Buffer, "le", 4)],
+        program.programId
+      );
+
       const tx = await program.methods
-        .updateInvestorData(Array.from(vaultId), TOTAL_ALLOCATION)
+        .updateInvestorData(Array.from(vaultId), 0, investors)
         .accounts({
           vault,
+          investorPage,
           authority: provider.wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
-        .remainingAccounts([
-          // In real implementation, would pass investor records
-        ])
+        .remainingAccounts(
+          investorRecords.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false }))
+        )
         .rpc();
       
       console.log("Update investor data transaction:", tx);
       
-      // Verify vault was updated
+      // Verify Y0 equals the sum of registered allocations
       const vaultAccount = await program.account.vault.fetch(vault);
+      assert.equal(vaultAccount.investorCount, 3);
       assert.equal(
-        vaultAccount.totalInvestorAllocation.toNumber(),
-        TOTAL_ALLOCATION.toNumber()
+        vaultAccount.totalInvestorAllocation.toString(),
+        TOTAL_ALLOCATION.divn(3).muln(3).toString()
       );
+
+      const pageAccount = await program.account.investorPage.fetch(investorPage);
+      assert.equal(pageAccount.investorCount, 3);
+      assert.deepEqual(
+        pageAccount.investors.map((k) => k.toBase58()),
+        investorRecords.map((k) => k.toBase58())
+      );
+
+      const record = await program.account.investorRecord.fetch(investorRecords[1]);
+      assert.equal(record.page, 0);
+      assert.equal(record.pageIndex, 1);
     });
   });
   
+  describe("Vault Config Timelock", () => {
+    const newConfig = () => ({
+      creatorWallet: creatorWallet.publicKey,
+      investorFeeShareBps: 4000,
+      minPayoutLamports: new BN(MIN_PAYOUT_LAMPORTS),
+      dailyCapLamports: DAILY_CAP_LAMPORTS,
+      ataRentReimbursement: new BN(0),
+    });
+    const DELAY_SECS = 7 * 86400;
+
+    it("Should reject changes effective before the timelock delay", async () => {
+      const now = Math.floor(Date.now() / 1000);
+      try {
+        await program.methods
+          .queueVaultConfig(Array.from(vaultId), newConfig(), new BN(now + 3600))
+          .accounts({ vault, admin: provider.wallet.publicKey })
+          .rpc();
+
+        assert.fail("Should have enforced the timelock delay");
+      } catch (error) {
+        assert.include(error.toString(), "ConfigChangeTooSoon");
+      }
+    });
+
+    it("Should queue, refuse early execution and cancel a change", async () => {
+      const effectiveTs = Math.floor(Date.now() / 1000) + DELAY_SECS + 3600;
+
+      await program.methods
+        .queueVaultConfig(Array.from(vaultId), newConfig(), new BN(effectiveTs))
+        .accounts({ vault, admin: provider.wallet.publicKey })
+        .rpc();
+
+      let vaultAccount = await program.account.vault.fetch(vault);
+      assert.equal(vaultAccount.pendingConfig.config.investorFeeShareBps, 4000);
+      assert.equal(vaultAccount.pendingConfig.effectiveTs.toNumber(), effectiveTs);
+      assert.equal(vaultAccount.investorFeeShareBps, INVESTOR_FEE_SHARE_BPS);
+
+      try {
+        await program.methods
+          .executeVaultConfig(Array.from(vaultId))
+          .accounts({ vault, distributionState, executor: investor1.publicKey })
+          .signers([investor1])
+          .rpc();
+
+        assert.fail("Should have enforced the timelock");
+      } catch (error) {
+        assert.include(error.toString(), "ConfigTimelockNotElapsed");
+      }
+
+      await program.methods
+        .cancelVaultConfig(Array.from(vaultId))
+        .accounts({ vault, admin: provider.wallet.publicKey })
+        .rpc();
+
+      vaultAccount = await program.account.vault.fetch(vault);
+      assert.isNull(vaultAccount.pendingConfig);
+    });
+
+    it("Should reject config changes queued by non-admins", async () => {
+      const effectiveTs = Math.floor(Date.now() / 1000) + DELAY_SECS + 3600;
+      try {
+        await program.methods
+          .queueVaultConfig(Array.from(vaultId), newConfig(), new BN(effectiveTs))
+          .accounts({ vault, admin: investor1.publicKey })
+          .signers([investor1])
+          .rpc();
+
+        assert.fail("Should have rejected a non-admin signer");
+      } catch (error) {
+        assert.include(error.toString(), "Unauthorized");
+      }
+    });
+  });
+
+  describe("Pause", () => {
+    it("Should let the guardian pause and block distribution while paused", async () => {
+      await program.methods
+        .setGuardian(Array.from(vaultId), investor2.publicKey)
+        .accounts({ vault, admin: provider.wallet.publicKey })
+        .rpc();
+
+      await program.methods
+        .setPaused(Array.from(vaultId), true)
+        .accounts({ vault, authority: investor2.publicKey })
+        .signers([investor2])
+        .rpc();
+
+      let vaultAccount = await program.account.vault.fetch(vault);
+      assert.isTrue(vaultAccount.paused);
+
+      try {
+        await program.methods
+          .snapshotInvestorPage(Array.from(vaultId), 0)
+          .accounts({
+            vault,
+            distributionState,
+            investorPage: PublicKey.findProgramAddressSync(
+              [Buffer.from("investor_page"), vaultId, new BN(0).toArrayLike(Buffer, "le", 4)],
+              program.programId
+            )[0],
+            crankOperator: provider.wallet.publicKey,
+          })
+          .rpc();
+
+        assert.fail("Should have rejected a paused vault");
+      } catch (error) {
+        assert.include(error.toString(), "VaultPaused");
+      }
+
+      // The admin can always unpause
+      await program.methods
+        .setPaused(Array.from(vaultId), false)
+        .accounts({ vault, authority: provider.wallet.publicKey })
+        .rpc();
+
+      vaultAccount = await program.account.vault.fetch(vault);
+      assert.isFalse(vaultAccount.paused);
+    });
+
+    it("Should reject pausing by other keys", async () => {
+      try {
+        await program.methods
+          .setPaused(Array.from(vaultId), true)
+          .accounts({ vault, authority: investor1.publicKey })
+          .signers([investor1])
+          .rpc();
+
+        assert.fail("Should have rejected a non-guardian signer");
+      } catch (error) {
+        assert.include(error.toString(), "Unauthorized");
+      }
+    });
+  });
+
   describe("Distribute Fees", () => {
+    it("Should only snapshot a page against its registered streams", async () => {
+      try {
+        await program.methods
+          .snapshotInvestorPage(Array.from(vaultId), 0)
+          .accounts({
+            vault,
+            distributionState,
+            investorPage: PublicKey.findProgramAddressSync(
+              [Buffer.from("investor_page"), vaultId, new BN(0).toArrayLike(Buffer, "le", 4)],
+              program.programId
+            )[0],
+            crankOperator: provider.wallet.publicKey,
+          })
+          .remainingAccounts(
+            // [investor_record, stream] per registered investor
+            [investor1, investor2, investor3].flatMap((investor) => [
+              {
+                pubkey: PublicKey.findProgramAddressSync(
+                  [Buffer.from("investor_record"), vaultId, investor.publicKey.toBuffer()],
+                  program.programId
+                )[0],
+                isWritable: false,
+                isSigner: false,
+              },
+              { pubkey: Keypair.generate().publicKey, isWritable: false, isSigner: false }, // Mock stream
+            ])
+          )
+          .rpc();
+        
+        assert.fail("Should have rejected unregistered stream accounts");
+      } catch (error) {
+        assert.include(error.toString(), "InvalidStreamAccount");
+      }
+    });
+    
+    it("Should only claim a position through its registered accounts", async () => {
+      try {
+        await program.methods
+          .claimPositionFees(Array.from(vaultId), 0)
+          .accounts({
+            vault,
+            distributionState,
+            feePositionRecord: PublicKey.findProgramAddressSync(
+              [Buffer.from("fee_position"), vaultId, new BN(0).toArrayLike(Buffer, "le", 4)],
+              program.programId
+            )[0],
+            treasuryQuote,
+            treasuryBase,
+            feePosition: Keypair.generate().publicKey, // Not the registered position
+            feePositionOwner,
+            pool: mockPool.publicKey,
+            reserveX: Keypair.generate().publicKey,
+            reserveY: Keypair.generate().publicKey,
+            binArrayLower: Keypair.generate().publicKey,
+            binArrayUpper: Keypair.generate().publicKey,
+            tokenXMint: baseMint,
+            tokenYMint: quoteMint,
+            eventAuthority: PublicKey.findProgramAddressSync(
+              [Buffer.from("__event_authority")],
+              new PublicKey("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo")
+            )[0],
+            dlmmProgram: new PublicKey("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo"),
+            crankOperator: provider.wallet.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            baseTokenProgram: TOKEN_PROGRAM_ID,
+            memoProgram: null,
+          })
+          .rpc();
+        
+        assert.fail("Should have rejected an unregistered position");
+      } catch (error) {
+        assert.include(error.toString(), "ConstraintRaw");
+      }
+    });
+    
+    it("Should only let the admin remove a fee position", async () => {
+      try {
+        await program.methods
+          .removeFeePosition(Array.from(vaultId), 0)
+          .accounts({
+            vault,
+            distributionState,
+            feePositionRecord: PublicKey.findProgramAddressSync(
+              [Buffer.from("fee_position"), vaultId, new BN(0).toArrayLike(Buffer, "le", 4)],
+              program.programId
+            )[0],
+            lastPositionRecord: null,
+            feePosition: Keypair.generate().publicKey,
+            pool: Keypair.generate().publicKey,
+            binArrayLower: Keypair.generate().publicKey,
+            binArrayUpper: Keypair.generate().publicKey,
+            eventAuthority: PublicKey.findProgramAddressSync(
+              [Buffer.from("__event_authority")],
+              new PublicKey("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo")
+            )[0],
+            dlmmProgram: new PublicKey("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo"),
+            admin: investor1.publicKey,
+          })
+          .signers([investor1])
+          .rpc();
+        
+        assert.fail("Should have rejected a non-admin signer");
+      } catch (error) {
+        assert.include(error.toString(), "Unauthorized");
+      }
+    });
+    
     it("Should enforce 24h distribution window", async () => {
       // Create treasury account for the vault
       const treasuryAccount = treasuryQuote;
@@ -456,37 +848,74 @@ describe("dlmm-fee-router", () => {
       );
       
       try {
-        // Try to distribute immediately (should fail due to 24h window)
+        // Try to open a day immediately (should fail due to 24h window)
         await program.methods
-          .distributeFees(Array.from(vaultId), 0, false)
+          .claimDayFees(Array.from(vaultId))
           .accounts({
             vault,
             distributionState,
-            investorPage: Keypair.generate().publicKey,
             treasuryQuote: treasuryAccount,
-            treasuryBase: treasuryBase,
+            crankOperator: provider.wallet.publicKey,
+          })
+          .rpc();
+        
+        assert.fail("Should have enforced 24h window");
+      } catch (error) {
+        assert.include(error.toString(), "DistributionWindowNotReached");
+      }
+    });
+    
+    it("Should not pay a page before the day is opened", async () => {
+      try {
+        // No day has been opened with `claim_day_fees` yet
+        await program.methods
+          .distributePage(Array.from(vaultId), 0, false, false)
+          .accounts({
+            vault,
+            distributionState,
+            investorPage: PublicKey.findProgramAddressSync(
+              [Buffer.from("investor_page"), vaultId, new BN(0).toArrayLike(Buffer, "le", 4)],
+              program.programId
+            )[0],
+            pageBitmap: PublicKey.findProgramAddressSync(
+              [Buffer.from("page_bitmap"), vaultId, new BN(0).toArrayLike(Buffer, "le", 4)],
+              program.programId
+            )[0],
+            treasuryQuote,
             creatorQuoteAccount,
-            feePosition: mockFeePosition.publicKey,
             feePositionOwner,
-            dlmmProgram: new PublicKey("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo"),
             streamflowProgram: new PublicKey("strmRqUCoQUgGUan5YhzUZa6KqdzwX5L6FpUxfmKg5m"),
             quoteMint,
             crankOperator: provider.wallet.publicKey,
+            crankQuoteAccount: null,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
             clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
           })
           .remainingAccounts([
-            { pubkey: investor1QuoteAccount, isWritable: true, isSigner: false },
-            { pubkey: Keypair.generate().publicKey, isWritable: false, isSigner: false }, // Mock stream
-            { pubkey: investor2QuoteAccount, isWritable: true, isSigner: false },
-            { pubkey: Keypair.generate().publicKey, isWritable: false, isSigner: false }, // Mock stream
+            // [investor_record, investor_ata, stream] per registered investor
+            ...[
+              [investor1, investor1QuoteAccount],
+              [investor2, investor2QuoteAccount],
+              [investor3, investor3QuoteAccount],
+            ].flatMap(([investor, quoteAccount]: [Keypair, PublicKey]) => [
+              {
+                pubkey: PublicKey.findProgramAddressSync(
+                  [Buffer.from("investor_record"), vaultId, investor.publicKey.toBuffer()],
+                  program.programId
+                )[0],
+                isWritable: false,
+                isSigner: false,
+              },
+              { pubkey: quoteAccount, isWritable: true, isSigner: false },
+              { pubkey: Keypair.generate().publicKey, isWritable: false, isSigner: false }, // Mock stream
+            ]),
           ])
           .rpc();
         
-        assert.fail("Should have enforced 24h window");
+        assert.fail("Should have required an open day");
       } catch (error) {
-        assert.include(error.toString(), "DistributionWindowNotReached");
+        assert.include(error.toString(), "DayNotStarted");
       }
     });
     